@@ -0,0 +1,243 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+use fast_polynomial::{
+    accurate::poly_stable,
+    many_xs::{poly_array_sum, ArrayWrap},
+    poly, poly_array, poly_auto,
+    polynomials::*,
+    PolyNum,
+};
+
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+use fast_polynomial::preprocessed::PreprocessedPoly;
+
+/// Number of evaluations per iteration, used to report throughput.
+const EVALS: usize = 100;
+
+/// Generate deterministic coefficients in the range `[0.5, 1.5)` without pulling in an RNG.
+fn coeffs<F: From<f32> + Copy, const N: usize>() -> [F; N] {
+    let mut state = 0x9E37_79B9u32;
+    core::array::from_fn(|_| {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        F::from(0.5 + (state >> 8) as f32 / (1u32 << 24) as f32)
+    })
+}
+
+macro_rules! bench_degrees {
+    ($g:expr, $ty:ty, $x:expr; $($n:literal),*) => {$({
+        let c = black_box(coeffs::<$ty, $n>());
+        let x: $ty = black_box($x);
+
+        $g.bench_with_input(BenchmarkId::new("poly", $n), &$n, |b, _| {
+            b.iter(|| {
+                for _ in 0..EVALS {
+                    black_box(poly(black_box(x), &c[..]));
+                }
+            });
+        });
+
+        $g.bench_with_input(BenchmarkId::new("poly_array", $n), &$n, |b, _| {
+            b.iter(|| {
+                for _ in 0..EVALS {
+                    black_box(poly_array(black_box(x), &c));
+                }
+            });
+        });
+    })*};
+}
+
+#[rustfmt::skip]
+#[inline(always)]
+fn manual<F: PolyNum>(x: F, c: &[F; 32], n: usize) -> F {
+    let x2 = x * x;
+    let x4 = x2 * x2;
+    let x8 = x4 * x4;
+    let x16 = x8 * x8;
+
+    match n {
+        2  => poly_1(x, c[0], c[1]),
+        3  => poly_2(x, x2, c[0], c[1], c[2]),
+        4  => poly_3(x, x2, c[0], c[1], c[2], c[3]),
+        5  => poly_4(x, x2, x4, c[0], c[1], c[2], c[3], c[4]),
+        6  => poly_5(x, x2, x4, c[0], c[1], c[2], c[3], c[4], c[5]),
+        7  => poly_6(x, x2, x4, c[0], c[1], c[2], c[3], c[4], c[5], c[6]),
+        8  => poly_7(x, x2, x4, c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7]),
+        9  => poly_8(x, x2, x4, x8, c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7], c[8]),
+        10 => poly_9(x, x2, x4, x8, c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7], c[8], c[9]),
+        11 => poly_10(x, x2, x4, x8, c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7], c[8], c[9], c[10]),
+        12 => poly_11(x, x2, x4, x8, c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7], c[8], c[9], c[10], c[11]),
+        13 => poly_12(x, x2, x4, x8, c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7], c[8], c[9], c[10], c[11], c[12]),
+        14 => poly_13(x, x2, x4, x8, c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7], c[8], c[9], c[10], c[11], c[12], c[13]),
+        15 => poly_14(x, x2, x4, x8, c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7], c[8], c[9], c[10], c[11], c[12], c[13], c[14]),
+        16 => poly_15(x, x2, x4, x8, c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7], c[8], c[9], c[10], c[11], c[12], c[13], c[14], c[15]),
+        32 => poly_31(x, x2, x4, x8, x16,
+            c[0], c[1], c[2], c[3], c[4], c[5], c[6], c[7], c[8], c[9], c[10], c[11], c[12], c[13], c[14], c[15],
+            c[16], c[17], c[18], c[19], c[20], c[21], c[22], c[23], c[24], c[25], c[26], c[27], c[28], c[29], c[30], c[31]),
+        _ => unreachable!(),
+    }
+}
+
+fn bench_manual<F: PolyNum + From<f32>>(c: &mut Criterion, name: &str, x: F) {
+    let mut g = c.benchmark_group(format!("{name}/manual"));
+    g.throughput(Throughput::Elements(EVALS as u64));
+
+    let coeffs = black_box(coeffs::<F, 32>());
+
+    for n in [2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 32] {
+        g.bench_with_input(BenchmarkId::new("poly_N", n), &n, |b, &n| {
+            b.iter(|| {
+                for _ in 0..EVALS {
+                    black_box(manual(black_box(x), &coeffs, n));
+                }
+            });
+        });
+    }
+
+    g.finish();
+}
+
+/// Compare Estrin's scheme with Horner's method, to derive the thresholds used by `poly_auto`.
+fn bench_auto<F: PolyNum>(c: &mut Criterion, name: &str, x: f32, from: impl Fn(f32) -> F) {
+    let mut g = c.benchmark_group(format!("{name}/auto"));
+    g.throughput(Throughput::Elements(EVALS as u64));
+
+    let coeffs = black_box(coeffs::<f32, 64>().map(&from));
+    let x = from(x);
+
+    for n in [2, 4, 8, 12, 16, 24, 32, 64] {
+        let c = &coeffs[..n];
+
+        g.bench_with_input(BenchmarkId::new("estrin", n), &n, |b, _| {
+            b.iter(|| {
+                for _ in 0..EVALS {
+                    black_box(poly(black_box(x), c));
+                }
+            });
+        });
+
+        g.bench_with_input(BenchmarkId::new("horner", n), &n, |b, _| {
+            b.iter(|| {
+                for _ in 0..EVALS {
+                    black_box(poly_stable(black_box(x), c));
+                }
+            });
+        });
+
+        g.bench_with_input(BenchmarkId::new("poly_auto", n), &n, |b, _| {
+            b.iter(|| {
+                for _ in 0..EVALS {
+                    black_box(poly_auto(black_box(x), c));
+                }
+            });
+        });
+    }
+
+    g.finish();
+}
+
+/// Compare Horner's method with the preprocessed form, which trades `n - 1` multiplications
+/// for `⌊(n - 1) / 2⌋ + 2`, noted in the benchmark names.
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+fn bench_preprocessed(c: &mut Criterion) {
+    let mut g = c.benchmark_group("f64/preprocessed");
+    g.throughput(Throughput::Elements(EVALS as u64));
+
+    let coeffs = black_box(coeffs::<f64, 16>());
+    let x = 0.5;
+
+    for n in [4, 6, 8, 12, 16] {
+        let c = &coeffs[..n];
+        let p = PreprocessedPoly::new(c).expect("preprocessing failed");
+
+        g.bench_with_input(
+            BenchmarkId::new(format!("horner/{}mul", n - 1), n),
+            &n,
+            |b, _| {
+                b.iter(|| {
+                    for _ in 0..EVALS {
+                        black_box(poly_stable(black_box(x), c));
+                    }
+                });
+            },
+        );
+
+        let name = format!("preprocessed/{}mul", p.multiplications());
+
+        g.bench_with_input(BenchmarkId::new(name, n), &n, |b, _| {
+            b.iter(|| {
+                for _ in 0..EVALS {
+                    black_box(p.eval(black_box(x)));
+                }
+            });
+        });
+    }
+
+    g.finish();
+}
+
+fn bench_sum(c: &mut Criterion) {
+    let mut g = c.benchmark_group("f64/sum");
+
+    let xs: Vec<f64> = (0..1024).map(|i| i as f64 / 1024.0).collect();
+    let coeffs = black_box(coeffs::<f64, 8>());
+
+    g.throughput(Throughput::Elements(xs.len() as u64));
+
+    let mut buffer = vec![0.0; xs.len()];
+
+    g.bench_function("two_step", |b| {
+        b.iter(|| {
+            for (y, &x) in buffer.iter_mut().zip(black_box(&xs)) {
+                *y = poly(x, &coeffs);
+            }
+            black_box(buffer.iter().sum::<f64>())
+        });
+    });
+
+    g.bench_function("poly_array_sum<8>", |b| {
+        b.iter(|| black_box(poly_array_sum::<8, _>(black_box(&xs), &coeffs)));
+    });
+
+    g.finish();
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    {
+        let mut g = c.benchmark_group("f32");
+        g.throughput(Throughput::Elements(EVALS as u64));
+        #[rustfmt::skip]
+        bench_degrees!(g, f32, 0.5;
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+            33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64);
+        g.finish();
+    }
+
+    {
+        let mut g = c.benchmark_group("f64");
+        g.throughput(Throughput::Elements(EVALS as u64));
+        #[rustfmt::skip]
+        bench_degrees!(g, f64, 0.5;
+            1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 29, 30, 31, 32,
+            33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45, 46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64);
+        g.finish();
+    }
+
+    bench_manual::<f32>(c, "f32", 0.5);
+    bench_manual::<f64>(c, "f64", 0.5);
+
+    bench_auto(c, "f32", 0.5, |x| x);
+    bench_auto(c, "f64", 0.5, f64::from);
+    bench_auto(c, "ArrayWrap<16, f32>", 0.5, ArrayWrap::<16, f32>::splat);
+    bench_auto(c, "ArrayWrap<16, f64>", 0.5, |x| {
+        ArrayWrap::<16, f64>::splat(x.into())
+    });
+
+    #[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+    bench_preprocessed(c);
+    bench_sum(c);
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,49 @@
+/// Interpret the input as `x` followed by any number of coefficients, each as little-endian `f64`.
+///
+/// Any trailing bytes that do not form a whole `f64` are ignored.
+pub fn parse(data: &[u8]) -> Option<(f64, Vec<f64>)> {
+    let mut values = data
+        .chunks_exact(8)
+        .map(|bytes| f64::from_le_bytes(bytes.try_into().unwrap()));
+
+    let x = values.next()?;
+
+    Some((x, values.collect()))
+}
+
+/// Horner's method, as in the README.
+pub fn horners_method(x: f64, coeffs: &[f64]) -> f64 {
+    coeffs.iter().rev().fold(0.0, |sum, &c| x * sum + c)
+}
+
+/// Check `y` against Horner's method, if the evaluation is finite and a meaningful error bound exists.
+///
+/// Both Horner's method and Estrin's scheme have a forward error bounded by a small multiple of
+/// `n * EPSILON * Σ|c_k||x|^k`, with some slack for subnormal results and fused multiply-add.
+/// However, Estrin's scheme computes powers of `x` and partial sums that Horner's method does not,
+/// which may overflow or underflow on their own, so inputs where that is possible are skipped.
+pub fn check_poly(x: f64, coeffs: &[f64], y: f64) {
+    let n = coeffs.len();
+    let abs_coeffs: Vec<f64> = coeffs.iter().map(|c| c.abs()).collect();
+
+    // the hybrid loop computes powers up to x^64 with the `multi-accumulator` feature
+    let max_power = x.abs().powi(n.max(64) as i32);
+
+    if !x.is_finite()
+        || (x != 0.0 && !max_power.is_normal())
+        || !horners_method(x.abs().max(1.0), &abs_coeffs).is_finite()
+    {
+        return;
+    }
+
+    let reference = horners_method(x, coeffs);
+    let abs_sum = horners_method(x.abs(), &abs_coeffs);
+
+    let n = n as f64;
+    let bound = 4.0 * (n + 1.0) * f64::EPSILON * abs_sum + (n + 1.0) * f64::MIN_POSITIVE;
+
+    assert!(
+        (y - reference).abs() <= bound,
+        "x = {x:e}, y = {y:e}, reference = {reference:e}, bound = {bound:e}, coeffs = {coeffs:?}"
+    );
+}
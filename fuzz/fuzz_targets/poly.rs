@@ -0,0 +1,32 @@
+#![no_main]
+
+use fast_polynomial::{poly, poly_array, poly_f};
+use libfuzzer_sys::fuzz_target;
+
+mod common;
+
+macro_rules! check_arrays {
+    ($x:expr, $coeffs:expr; $($n:literal),*) => {$(
+        if let Some(c) = $coeffs.get(..$n) {
+            let c: &[f64; $n] = c.try_into().unwrap();
+            let y = poly_array($x, c);
+
+            // every path shares the same dispatch, so should be bit-identical
+            assert_eq!(y.to_bits(), poly($x, &c[..]).to_bits());
+            common::check_poly($x, c, y);
+        }
+    )*};
+}
+
+fuzz_target!(|data: &[u8]| {
+    let Some((x, coeffs)) = common::parse(data) else {
+        return;
+    };
+
+    let y = poly(x, &coeffs);
+
+    assert_eq!(y.to_bits(), poly_f(x, coeffs.len(), |i| coeffs[i]).to_bits());
+    common::check_poly(x, &coeffs, y);
+
+    check_arrays!(x, coeffs; 1, 2, 3, 4, 5, 8, 15, 16, 17, 31, 32, 33, 48, 64, 65);
+});
@@ -0,0 +1,41 @@
+#![no_main]
+
+use fast_polynomial::{poly, rational, rational_array};
+use libfuzzer_sys::fuzz_target;
+
+mod common;
+
+fuzz_target!(|data: &[u8]| {
+    // the first byte splits the coefficients between the numerator and denominator
+    let Some((&split, data)) = data.split_first() else {
+        return;
+    };
+
+    let Some((x, coeffs)) = common::parse(data) else {
+        return;
+    };
+
+    let (numerator, denominator) = coeffs.split_at((split as usize).min(coeffs.len()));
+
+    let y = rational(x, numerator, denominator);
+
+    // a NaN input propagates unless neither polynomial depends on x
+    if x.is_nan() && (numerator.len() > 1 || denominator.len() > 1) {
+        assert!(y.is_nan());
+    }
+
+    // without the reciprocal, the result is exactly the quotient of the two polynomials
+    if x.is_nan() || x.abs() <= 1.0 || (numerator.len() <= 2 && denominator.len() <= 2) {
+        let expected = poly(x, numerator) / poly(x, denominator);
+        assert!(y.to_bits() == expected.to_bits() || (y.is_nan() && expected.is_nan()));
+    }
+
+    if let (Some(n), Some(d)) = (numerator.get(..3), denominator.get(..4)) {
+        let n: &[f64; 3] = n.try_into().unwrap();
+        let d: &[f64; 4] = d.try_into().unwrap();
+
+        let y = rational_array(x, n, d);
+        let expected = rational(x, n, d);
+        assert!(y.to_bits() == expected.to_bits() || (y.is_nan() && expected.is_nan()));
+    }
+});
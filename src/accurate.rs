@@ -0,0 +1,320 @@
+//! Evaluation routines that trade speed for accuracy.
+//!
+//! The functions in this module are considerably slower than [`poly`](crate::poly) or
+//! [`poly_array`](crate::poly_array), and should only be used when the extra accuracy
+//! is actually required, such as for generating reference values or evaluating
+//! series prone to catastrophic cancellation.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+mod double_double;
+
+pub use double_double::DoubleDouble;
+
+use double_double::{two_prod, two_sum};
+
+#[cfg(feature = "alloc")]
+use num_traits::One;
+
+use crate::{fma, PolyNum, PolyRational};
+
+#[cfg(feature = "alloc")]
+use crate::{abs, PolyNumSigned};
+
+/// Evaluate a polynomial at `x = mantissa * 2^exp2`, returning the result in the same form.
+///
+/// Horner's method is performed with the running sum kept as a separate mantissa and exponent,
+/// so neither `x`, its powers, nor the result are ever formed as an `f64`, and cannot overflow or
+/// underflow no matter how extreme `exp2` is. This allows evaluating polynomials over dynamic ranges far
+/// beyond that of `f64`. The returned mantissa is either zero, non-finite, or has a magnitude in `[0.5, 1)`.
+///
+/// Each step renormalizes the sum, so this is considerably slower than [`poly`](crate::poly).
+/// Exponents saturate at the limits of `i32`.
+///
+/// ```rust
+/// use fast_polynomial::accurate::poly_scaled_input;
+///
+/// // 1 + x + x^2 at x = 2^2000, which is approximately 2^4000
+/// let (m, e) = poly_scaled_input(1.0, 2000, &[1.0, 1.0, 1.0]);
+///
+/// assert_eq!((m, e), (0.5, 4001));
+/// ```
+pub fn poly_scaled_input(mantissa: f64, exp2: i32, coeffs: &[f64]) -> (f64, i32) {
+    let (xm, xe) = frexp(mantissa);
+    let xe = xe.saturating_add(exp2);
+
+    let (mut m, mut e) = (0.0, 0i32);
+
+    for &c in coeffs.iter().rev() {
+        // multiply by x, keeping the product of the mantissas within [0.25, 1)
+        m *= xm;
+        e = e.saturating_add(xe);
+
+        // add the coefficient by aligning it to the larger exponent
+        let (cm, ce) = frexp(c);
+
+        if m == 0.0 {
+            (m, e) = (cm, ce);
+        } else if cm != 0.0 {
+            let max = e.max(ce);
+            m = ldexp(m, e.saturating_sub(max)) + ldexp(cm, ce.saturating_sub(max));
+            e = max;
+        }
+
+        let (nm, ne) = frexp(m);
+        m = nm;
+        e = e.saturating_add(ne);
+    }
+
+    (m, e)
+}
+
+/// Evaluate a polynomial for an array of coefficients with `f64` inputs, but with the rounding errors
+/// of the accumulation tracked in double-double precision. Can be monomorphized.
+///
+/// This is the compensated Horner scheme of Graillat, Langlois and Louvet: at each step the exact
+/// rounding errors of the product with `x` and the sum with the coefficient are computed with
+/// error-free transformations, and accumulated into a separate correction polynomial which is added
+/// to the result at the end. The result is as accurate as if Horner's method were performed in twice
+/// the working precision and then rounded to `f64`, with a relative error bound of roughly `u + cond · u²`,
+/// where `cond` is the condition number of the evaluation as given by
+/// [`poly_array_cond`](crate::diagnostics::poly_array_cond).
+///
+/// Unlike evaluating with [`DoubleDouble`], `x` and the coefficients remain `f64` values, so only the
+/// summation is extended, which is considerably cheaper. However, this is still several times slower
+/// than [`poly_array`](crate::poly_array), as Horner's method has a longer dependency chain than Estrin's scheme.
+///
+/// ```rust
+/// use fast_polynomial::{poly_array, accurate::poly_array_dd_acc};
+///
+/// // (x - 1)^5 expanded, evaluated close to its root where cancellation is severe
+/// let c = [-1.0, 5.0, -10.0, 10.0, -5.0, 1.0];
+/// let x = 1.001f64;
+///
+/// let exact = (x - 1.0).powi(5); // x - 1 is exact here
+///
+/// assert!((poly_array_dd_acc(x, &c) - exact).abs() < 1e-14 * exact);
+/// assert!((poly_array(x, &c) - exact).abs() > 1e-3 * exact);
+/// ```
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+pub fn poly_array_dd_acc<const N: usize>(x: f64, coeffs: &[f64; N]) -> f64 {
+    let Some((&last, rest)) = coeffs.split_last() else {
+        return 0.0;
+    };
+
+    let (mut sum, mut err) = (last, 0.0);
+
+    for &c in rest.iter().rev() {
+        let (p, pe) = two_prod(sum, x);
+        let (s, se) = two_sum(p, c);
+
+        sum = s;
+        err = err * x + (pe + se);
+    }
+
+    sum + err
+}
+
+/// Evaluate `2^exp2 * p(x)` for an array of coefficients, scaling each coefficient by `2^exp2` beforehand. Can be monomorphized.
+///
+/// Some FPUs, particularly on embedded targets or with "fast math" settings, run with flush-to-zero (FTZ)
+/// and denormals-are-zero (DAZ) modes enabled, replacing subnormal results and inputs with zero. Polynomials
+/// whose terms are near the subnormal range, below `2^-1022` for `f64`, will then give different results than on
+/// a host machine with full IEEE 754 gradual underflow, losing precision or returning zero entirely.
+///
+/// Scaling the coefficients by a power of two is exact, so choosing `exp2` large enough to lift the terms into
+/// the normal range avoids this, as well as the slow microcode paths some processors use for subnormals. The
+/// result is left scaled, as unscaling it would flush it to zero again on such targets, so should be compared
+/// against or combined with other values in the scaled domain, or unscaled only once it's known to be normal.
+///
+/// Coefficients that are themselves subnormal will still be flushed to zero under DAZ before they can be scaled,
+/// so should be stored pre-scaled instead. Powers of `x` are not scaled, so `|x|` should not be so small that
+/// `x^16` is subnormal while the corresponding terms remain significant.
+///
+/// Without the `std` or `libm` crate features, `f64` does not implement [`MulAdd`](num_traits::MulAdd), so each step
+/// is instead a separately-rounded multiply and add, as with [`poly_array_no_contract`](crate::poly_array_no_contract).
+///
+/// ```rust
+/// use fast_polynomial::{poly_array, accurate::poly_array_scaled};
+///
+/// let c = [3e-300, -2e-300, 1e-300];
+/// let x = 1e-10f64;
+///
+/// // the higher-degree terms are subnormal, and would be flushed to zero under FTZ
+/// assert!((c[2] * x * x).is_subnormal());
+///
+/// let y = poly_array_scaled(x, &c, 1000);
+///
+/// assert!((c[2] * 2f64.powi(1000) * x * x).is_normal());
+/// assert_eq!(y, poly_array(x, &c.map(|c| c * 2f64.powi(1000))));
+/// ```
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+pub fn poly_array_scaled<const N: usize>(x: f64, coeffs: &[f64; N], exp2: i32) -> f64 {
+    let scaled = coeffs.map(|c| ldexp(c, exp2));
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    return crate::poly_array(x, &scaled);
+
+    #[cfg(not(any(feature = "std", feature = "libm")))]
+    return crate::poly_array_unfused(x, &scaled);
+}
+
+/// Split `x` into a mantissa in `[0.5, 1)` and a power of two. Zero and non-finite values are returned as-is.
+fn frexp(x: f64) -> (f64, i32) {
+    if x == 0.0 || !x.is_finite() {
+        return (x, 0);
+    }
+
+    let bits = x.to_bits();
+    let exp = ((bits >> 52) & 0x7ff) as i32;
+
+    if exp == 0 {
+        // subnormal, scale into the normal range first
+        let (m, e) = frexp(x * f64::from_bits((1023 + 54) << 52));
+        return (m, e - 54);
+    }
+
+    (
+        f64::from_bits((bits & !(0x7ff << 52)) | (1022 << 52)),
+        exp - 1022,
+    )
+}
+
+/// Compute `m * 2^e` without overflowing intermediate powers of two.
+fn ldexp(mut m: f64, mut e: i32) -> f64 {
+    while e > 1023 {
+        m *= f64::from_bits(2046 << 52); // 2^1023
+        e -= 1023;
+    }
+
+    while e < -1022 {
+        if m == 0.0 {
+            return m;
+        }
+
+        m *= f64::from_bits(1 << 52); // 2^-1022
+        e += 1022;
+    }
+
+    m * f64::from_bits(((e + 1023) as u64) << 52)
+}
+
+/// Evaluate a polynomial using only Horner's method.
+///
+/// The hybrid Estrin scheme used by [`poly`](crate::poly) precomputes powers up to `x^16`, which
+/// can overflow to infinity for large `|x|` even when the coefficients decay fast enough for the
+/// result to be finite, resulting in infinite or `NaN` results. Horner's method never forms
+/// any power of `x` beyond the first, so only overflows if the result (or a partial sum of its
+/// highest-degree terms) does. Use this for large-magnitude inputs.
+///
+/// This has a longer dependency chain than [`poly`](crate::poly), so is slower
+/// for high-degree polynomials, but is still a simple loop without allocation.
+///
+/// ```rust
+/// use fast_polynomial::{poly, accurate::poly_stable};
+///
+/// // c_k = max(2^(-64k), 2^-1074), such that every term is between 1 and 2^974 at x = 2^64
+/// let c: [f64; 33] = core::array::from_fn(|k| 0.5f64.powi(64 * k as i32).max(f64::from_bits(1)));
+/// let x = 2f64.powi(64);
+///
+/// assert!(!poly(x, &c).is_finite()); // x^16 overflowed
+/// assert!(poly_stable(x, &c).is_finite());
+/// ```
+#[inline]
+pub fn poly_stable<F: PolyNum>(x: F, coeffs: &[F]) -> F {
+    let mut sum = F::zero();
+
+    for &c in coeffs.iter().rev() {
+        sum = fma(sum, x, c);
+    }
+
+    sum
+}
+
+/// Evaluate a polynomial for an array of coefficients, returning `(y, k)` such that `p(x) = y * x^k`.
+///
+/// For `|x| <= 1`, this is just `(poly_array(x, coeffs), 0)`. Otherwise, the highest power of `x` is factored out,
+/// and the reversed polynomial is evaluated at `1/x` instead, the same as is done for the rational polynomials:
+///
+/// ```text
+/// c_0 + c_1 x + ... + c_n x^n = x^n (c_n + c_(n-1) x^-1 + ... + c_0 x^-n)
+/// ```
+///
+/// As no power of `x` greater than one is ever formed, the result is only infinite if the sum itself overflows,
+/// even when `x^n` would not be representable. This allows `f32` in particular to evaluate high-degree polynomials
+/// at large inputs, with the result combined with `x^k` in log-space, or after scaling by other factors.
+///
+/// Trailing zero coefficients are skipped before reversing, so `k` is the degree of the polynomial when `|x| > 1`.
+///
+/// ```rust
+/// use fast_polynomial::{poly_array, accurate::poly_array_factored};
+///
+/// // 1 + x + ... + x^40 at x = 10, which is approximately 1.111e40
+/// let c = [1.0f32; 41];
+/// let x = 10.0f32;
+///
+/// assert!(!poly_array(x, &c).is_finite());
+///
+/// let (y, k) = poly_array_factored(x, &c);
+///
+/// assert_eq!(k, 40);
+/// assert!((y.log10() + k as f32 * x.log10() - 40.045757).abs() < 1e-5);
+/// ```
+pub fn poly_array_factored<F: PolyRational, const N: usize>(x: F, coeffs: &[F; N]) -> (F, i32) {
+    let one = F::one();
+
+    if !(x > one || x < -one) {
+        return (crate::poly_array(x, coeffs), 0);
+    }
+
+    let Some(d) = coeffs.iter().rposition(|c| !c.is_zero()) else {
+        return (F::zero(), 0);
+    };
+
+    let mut reversed = *coeffs;
+    reversed.reverse();
+
+    (crate::poly(one / x, &reversed[N - 1 - d..]), d as i32)
+}
+
+/// Evaluate a polynomial by summing its individual terms from smallest to largest magnitude.
+///
+/// Horner's method and Estrin's scheme both effectively accumulate the largest terms first,
+/// which can lose accuracy for alternating series such as the Taylor expansion of `exp(-x)`,
+/// where large terms of opposite sign cancel out. Summing the smallest terms first allows
+/// their contributions to accumulate before being absorbed into the larger terms.
+///
+/// Each term `c_k * x^k` is computed individually and buffered, so this requires an allocation
+/// and a sort, making it _much_ slower than [`poly`](crate::poly).
+///
+/// Terms that cannot be compared (such as `NaN`) are treated as equal to every other term,
+/// so they still propagate to the result.
+#[cfg(feature = "alloc")]
+pub fn poly_sorted<F>(x: F, coeffs: &[F]) -> F
+where
+    F: PolyNumSigned + One + PartialOrd,
+{
+    let mut terms = Vec::with_capacity(coeffs.len());
+
+    let mut xk = F::one();
+    for &c in coeffs {
+        terms.push(c * xk);
+        xk = xk * x;
+    }
+
+    terms.sort_unstable_by(|a, b| {
+        abs(*a)
+            .partial_cmp(&abs(*b))
+            .unwrap_or(core::cmp::Ordering::Equal)
+    });
+
+    let mut sum = F::zero();
+    for t in terms {
+        sum = sum + t;
+    }
+
+    sum
+}
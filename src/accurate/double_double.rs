@@ -0,0 +1,204 @@
+use core::cmp::Ordering;
+use core::ops::{Add, Div, Mul, Neg, Sub};
+use num_traits::{MulAdd, One, Zero};
+
+/// Computes `a + b` and the exact rounding error of the sum.
+#[inline(always)]
+pub(crate) fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    let bb = s - a;
+    (s, (a - (s - bb)) + (b - bb))
+}
+
+/// Computes `a + b` and the exact rounding error of the sum, assuming `|a| >= |b|`.
+#[inline(always)]
+pub(crate) fn quick_two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    (s, b - (s - a))
+}
+
+/// Dekker's split of a 53-bit float into two non-overlapping 26-bit halves.
+#[inline(always)]
+fn split(a: f64) -> (f64, f64) {
+    let t = 134217729.0 * a; // 2^27 + 1
+    let hi = t - (t - a);
+    (hi, a - hi)
+}
+
+/// Computes `a * b` and the exact rounding error of the product.
+///
+/// This uses Dekker's algorithm rather than fused multiply-add, so is exact
+/// (barring overflow) regardless of target features or the `std`/`libm` features.
+#[inline(always)]
+pub(crate) fn two_prod(a: f64, b: f64) -> (f64, f64) {
+    let p = a * b;
+    let (ah, al) = split(a);
+    let (bh, bl) = split(b);
+    (p, ((ah * bh - p) + ah * bl + al * bh) + al * bl)
+}
+
+/// A double-double floating-point number, represented as the unevaluated sum of two `f64`s.
+///
+/// This provides roughly 106 bits of precision, at a significant performance cost compared
+/// to `f64`, but much less than arbitrary-precision arithmetic. It implements [`PolyNum`](crate::PolyNum)
+/// and [`PolyRational`](crate::PolyRational), so can be used with any evaluation routine in this
+/// crate, such as for generating high-precision reference values.
+///
+/// The arithmetic is implemented with error-free transformations that do not require fused
+/// multiply-add, so results are identical on every platform.
+///
+/// ```rust
+/// use fast_polynomial::{poly_array, accurate::DoubleDouble};
+///
+/// // (x - 1)^4 near x = 1 suffers from catastrophic cancellation in f64
+/// let c = [1.0, -4.0, 6.0, -4.0, 1.0].map(DoubleDouble::from);
+/// let x = 1.0 + 2f64.powi(-20);
+///
+/// assert_eq!(poly_array(DoubleDouble::from(x), &c).to_f64(), 2f64.powi(-80));
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct DoubleDouble {
+    hi: f64,
+    lo: f64,
+}
+
+impl DoubleDouble {
+    /// Create a new `DoubleDouble` from the unevaluated sum `hi + lo`, normalizing it.
+    #[inline]
+    pub fn new(hi: f64, lo: f64) -> Self {
+        let (hi, lo) = two_sum(hi, lo);
+        DoubleDouble { hi, lo }
+    }
+
+    /// The high (leading) component.
+    #[inline]
+    pub fn hi(self) -> f64 {
+        self.hi
+    }
+
+    /// The low (trailing) component.
+    #[inline]
+    pub fn lo(self) -> f64 {
+        self.lo
+    }
+
+    /// Round to the nearest `f64`.
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        self.hi + self.lo
+    }
+}
+
+impl From<f64> for DoubleDouble {
+    #[inline]
+    fn from(x: f64) -> Self {
+        DoubleDouble { hi: x, lo: 0.0 }
+    }
+}
+
+impl From<f32> for DoubleDouble {
+    #[inline]
+    fn from(x: f32) -> Self {
+        DoubleDouble::from(x as f64)
+    }
+}
+
+impl Add for DoubleDouble {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        let (s1, s2) = two_sum(self.hi, rhs.hi);
+        let (t1, t2) = two_sum(self.lo, rhs.lo);
+        let (s1, s2) = quick_two_sum(s1, s2 + t1);
+        let (hi, lo) = quick_two_sum(s1, s2 + t2);
+        DoubleDouble { hi, lo }
+    }
+}
+
+impl Sub for DoubleDouble {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl Neg for DoubleDouble {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        DoubleDouble {
+            hi: -self.hi,
+            lo: -self.lo,
+        }
+    }
+}
+
+impl Mul for DoubleDouble {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        let (p1, p2) = two_prod(self.hi, rhs.hi);
+        let (hi, lo) = quick_two_sum(p1, p2 + (self.hi * rhs.lo + self.lo * rhs.hi));
+        DoubleDouble { hi, lo }
+    }
+}
+
+impl Div for DoubleDouble {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        // long division, refining the quotient one f64 at a time
+        let q1 = self.hi / rhs.hi;
+        let r = self - rhs * DoubleDouble::from(q1);
+        let q2 = r.hi / rhs.hi;
+        let r = r - rhs * DoubleDouble::from(q2);
+        let q3 = r.hi / rhs.hi;
+
+        let (hi, lo) = quick_two_sum(q1, q2);
+        DoubleDouble { hi, lo } + DoubleDouble::from(q3)
+    }
+}
+
+impl MulAdd for DoubleDouble {
+    type Output = Self;
+
+    #[inline]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        self * a + b
+    }
+}
+
+impl Zero for DoubleDouble {
+    #[inline]
+    fn zero() -> Self {
+        DoubleDouble { hi: 0.0, lo: 0.0 }
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.hi == 0.0
+    }
+}
+
+impl One for DoubleDouble {
+    #[inline]
+    fn one() -> Self {
+        DoubleDouble { hi: 1.0, lo: 0.0 }
+    }
+}
+
+impl PartialOrd for DoubleDouble {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match self.hi.partial_cmp(&other.hi) {
+            Some(Ordering::Equal) => self.lo.partial_cmp(&other.lo),
+            ord => ord,
+        }
+    }
+}
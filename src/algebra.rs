@@ -0,0 +1,329 @@
+//! Operations on the coefficients of polynomials.
+//!
+//! Coefficients are always in order of increasing degree, such that `coeffs[k]` is the
+//! coefficient of `x^k`, matching the evaluation routines of this crate. Functions here
+//! produce new coefficients which can then be passed to those evaluation routines.
+
+use core::fmt;
+use core::ops::{Div, Neg};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+use num_traits::One;
+
+use crate::{fma, PolyNum, PolyNumSigned};
+
+/// Returns the degree of a polynomial, the index of its last non-zero coefficient,
+/// or `None` if all coefficients are zero or there are none.
+#[inline]
+pub fn degree<F: PolyNum>(coeffs: &[F]) -> Option<usize> {
+    coeffs.iter().rposition(|c| !c.is_zero())
+}
+
+/// Returns the leading coefficient of a polynomial, its last non-zero coefficient,
+/// or `None` if all coefficients are zero or there are none.
+#[inline]
+pub fn leading_coeff<F: PolyNum>(coeffs: &[F]) -> Option<F> {
+    degree(coeffs).map(|d| coeffs[d])
+}
+
+/// Divide every coefficient of a polynomial by its leading coefficient in-place, making it monic,
+/// and return the leading coefficient it was divided by.
+///
+/// If all coefficients are zero or there are none, they are left unchanged and `None` is returned.
+///
+/// ```rust
+/// use fast_polynomial::algebra::make_monic;
+///
+/// let mut c = [2.0, -4.0, 4.0, 0.0];
+///
+/// assert_eq!(make_monic(&mut c), Some(4.0));
+/// assert_eq!(c, [0.5, -1.0, 1.0, 0.0]);
+/// ```
+#[inline]
+pub fn make_monic<F: PolyNum + Div<Output = F>>(coeffs: &mut [F]) -> Option<F> {
+    let d = degree(coeffs)?;
+    let lc = coeffs[d];
+
+    for c in &mut coeffs[..=d] {
+        *c = *c / lc;
+    }
+
+    Some(lc)
+}
+
+/// Write the coefficients of the derivative of a polynomial into `out`, such that `out[k] = (k + 1) * coeffs[k + 1]`.
+///
+/// The multipliers `k + 1` are computed by repeatedly adding one, rather than by conversion,
+/// so this works for any number type, and is exact for floats up to `2^24` or `2^53` coefficients.
+///
+/// # Panics
+///
+/// Panics if `out.len()` is not `coeffs.len() - 1`, or zero if `coeffs` is empty.
+pub fn differentiate<F: PolyNum + One>(coeffs: &[F], out: &mut [F]) {
+    assert_eq!(
+        out.len(),
+        coeffs.len().saturating_sub(1),
+        "output must have one less coefficient"
+    );
+
+    let mut k = F::zero();
+
+    for (o, &c) in out.iter_mut().zip(coeffs.iter().skip(1)) {
+        k = k + F::one();
+        *o = k * c;
+    }
+}
+
+/// Shift a polynomial in-place such that the new coefficients describe `p(x + b)`.
+///
+/// This uses the `O(n^2)` repeated synthetic division algorithm, which only requires
+/// addition and multiplication.
+pub fn taylor_shift<F: PolyNum>(coeffs: &mut [F], b: F) {
+    let n = coeffs.len();
+
+    for i in 0..n {
+        for j in (i..n.saturating_sub(1)).rev() {
+            coeffs[j] = fma(b, coeffs[j + 1], coeffs[j]);
+        }
+    }
+}
+
+/// Compose a polynomial in-place with an affine map, such that the new coefficients describe `p(a * x + b)`.
+///
+/// This is a [`taylor_shift`] by `b` followed by scaling each coefficient `k` by `a^k`. When evaluating
+/// the same affine-mapped polynomial at many points, this avoids recomputing `a * x + b` each time,
+/// but may lose some accuracy for large shifts. See also [`poly_affine`](crate::poly_affine).
+pub fn compose_affine<F: PolyNum>(coeffs: &mut [F], a: F, b: F) {
+    taylor_shift(coeffs, b);
+
+    let mut ak = a;
+    for c in coeffs.iter_mut().skip(1) {
+        *c = *c * ak;
+        ak = ak * a;
+    }
+}
+
+/// Compose two polynomials, writing the coefficients of `p(q(x))` into `out`.
+///
+/// This is Horner's method applied to polynomials rather than values, where each step multiplies
+/// the running polynomial by `q` and adds the next coefficient of `p`. The multiplication is performed
+/// in-place within `out`, so no additional memory is required. This requires `O(deg(p)^2 deg(q)^2)` operations.
+///
+/// # Panics
+///
+/// Panics if `out.len()` is not `deg(p) * deg(q) + 1`, where the degree of
+/// an empty slice of coefficients is taken to be zero.
+pub fn compose<F: PolyNum>(p: &[F], q: &[F], out: &mut [F]) {
+    let dp = p.len().saturating_sub(1);
+    let dq = q.len().saturating_sub(1);
+
+    assert_eq!(
+        out.len(),
+        dp * dq + 1,
+        "output must have length deg(p) * deg(q) + 1"
+    );
+
+    out.fill(F::zero());
+
+    let Some((&last, rest)) = p.split_last() else {
+        return;
+    };
+
+    out[0] = last;
+
+    // degree of the running polynomial in out
+    let mut d = 0;
+
+    for &c in rest.iter().rev() {
+        let nd = d + dq;
+
+        // multiply by q from the highest degree down, so each out[i] is only
+        // overwritten after all the terms that read it have been computed
+        for i in (0..=nd).rev() {
+            let mut sum = F::zero();
+
+            for (j, &qj) in q.iter().enumerate().take(i + 1) {
+                if i - j <= d {
+                    sum = fma(out[i - j], qj, sum);
+                }
+            }
+
+            out[i] = sum;
+        }
+
+        out[0] = out[0] + c;
+        d = nd;
+    }
+}
+
+/// Replace `a` in-place with the remainder of the polynomial division `a / b`.
+///
+/// After this, every coefficient of `a` at or above the degree of `b` is zero. The leading
+/// coefficient of each step is explicitly set to zero rather than computed, so this always terminates,
+/// even when rounding error would otherwise prevent exact cancellation.
+///
+/// # Panics
+///
+/// Panics if `b` is the zero polynomial.
+pub fn poly_rem<F>(a: &mut [F], b: &[F])
+where
+    F: PolyNumSigned + Div<Output = F>,
+{
+    let db = degree(b).expect("division by the zero polynomial");
+    let lb = b[db];
+
+    while let Some(da) = degree(a) {
+        if da < db {
+            break;
+        }
+
+        let q = a[da] / lb;
+        let shift = da - db;
+
+        for (i, &bi) in b[..db].iter().enumerate() {
+            a[shift + i] = a[shift + i] - q * bi;
+        }
+
+        a[da] = F::zero();
+    }
+}
+
+/// Compute the greatest common divisor of two polynomials with the Euclidean algorithm,
+/// writing the monic (leading coefficient of one) result into `out` and returning its degree.
+///
+/// Any coefficients of `out` beyond the degree of the result are set to zero. If both `a` and
+/// `b` are zero polynomials, `out` is entirely zeroed and zero is returned.
+///
+/// Common factors are only found when remainders cancel _exactly_, so for floating-point
+/// coefficients subject to rounding error, this will often find only a constant (degree zero)
+/// result, as if the polynomials had no factors in common. This is always safe, as it just means
+/// no factors would be cancelled.
+///
+/// # Panics
+///
+/// Panics if `out` is too short to hold the result.
+#[cfg(feature = "alloc")]
+pub fn poly_gcd<F>(a: &[F], b: &[F], out: &mut [F]) -> usize
+where
+    F: PolyNumSigned + Div<Output = F>,
+{
+    let mut x: Vec<F> = a.to_vec();
+    let mut y: Vec<F> = b.to_vec();
+
+    while degree(&y).is_some() {
+        poly_rem(&mut x, &y);
+        core::mem::swap(&mut x, &mut y);
+    }
+
+    out.fill(F::zero());
+
+    let Some(d) = degree(&x) else {
+        return 0;
+    };
+
+    assert!(out.len() > d, "output too short for the degree {d} result");
+
+    let lc = x[d];
+    for (o, &c) in out.iter_mut().zip(&x[..=d]) {
+        *o = c / lc;
+    }
+
+    d
+}
+
+/// Format a polynomial for display, such as `3x^2 + 2x - 1`, from highest to lowest degree.
+///
+/// Zero terms are skipped, negative coefficients are written as subtraction, and coefficients of one
+/// are omitted for all but the constant term. If every coefficient is zero, or there are none, `0` is written.
+/// The precision of the formatter is applied to each coefficient, and the alternate flag uses superscripts
+/// for the exponents instead:
+///
+/// ```rust
+/// use fast_polynomial::algebra::display;
+///
+/// let c = [-1.0, 2.0, 0.0, 3.0, -1.0];
+///
+/// assert_eq!(display(&c).to_string(), "-x^4 + 3x^3 + 2x - 1");
+/// assert_eq!(format!("{:#.1}", display(&c)), "-x⁴ + 3.0x³ + 2.0x - 1.0");
+/// ```
+#[inline]
+pub fn display<F>(coeffs: &[F]) -> PolyDisplay<'_, F> {
+    PolyDisplay(coeffs)
+}
+
+/// Displays a polynomial, as returned by [`display`].
+#[derive(Debug, Clone, Copy)]
+pub struct PolyDisplay<'a, F>(pub &'a [F]);
+
+impl<F> fmt::Display for PolyDisplay<'_, F>
+where
+    F: fmt::Display + PolyNum + One + PartialOrd + Neg<Output = F>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const SUPERSCRIPTS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+
+        let mut first = true;
+
+        for (k, &c) in self.0.iter().enumerate().rev() {
+            if c.is_zero() {
+                continue;
+            }
+
+            let negative = c < F::zero();
+            let magnitude = if negative { -c } else { c };
+
+            match (first, negative) {
+                (true, true) => f.write_str("-")?,
+                (true, false) => {}
+                (false, true) => f.write_str(" - ")?,
+                (false, false) => f.write_str(" + ")?,
+            }
+
+            first = false;
+
+            if k == 0 || magnitude != F::one() {
+                match f.precision() {
+                    Some(precision) => write!(f, "{magnitude:.precision$}")?,
+                    None => write!(f, "{magnitude}")?,
+                }
+            }
+
+            if k == 0 {
+                continue;
+            }
+
+            f.write_str("x")?;
+
+            if k == 1 {
+                continue;
+            }
+
+            if f.alternate() {
+                // write the digits of k from most to least significant
+                let mut digits = [0u8; 20];
+                let mut n = 0;
+                let mut e = k;
+
+                while e > 0 {
+                    digits[n] = (e % 10) as u8;
+                    e /= 10;
+                    n += 1;
+                }
+
+                for &d in digits[..n].iter().rev() {
+                    write!(f, "{}", SUPERSCRIPTS[d as usize])?;
+                }
+            } else {
+                write!(f, "^{k}")?;
+            }
+        }
+
+        if first {
+            f.write_str("0")?;
+        }
+
+        Ok(())
+    }
+}
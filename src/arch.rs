@@ -0,0 +1,161 @@
+//! Hardware fused multiply-add without `std`.
+//!
+//! Without `std`, `num-traits` can only implement [`MulAdd`] for `f32` and `f64` via `libm`,
+//! whose `fma` and `fmaf` are out-of-line calls. On `x86_64` these select the hardware instruction
+//! at runtime, through a cached function pointer with a software fallback, and on `aarch64` they use
+//! `fmadd`, but either way every multiply-add is a call that cannot be inlined into the evaluation.
+//! Because those implementations cannot be replaced, this module provides the [`Fused`] wrapper type,
+//! whose [`MulAdd`] implementation calls the `core::arch` intrinsics directly, inlined as a single instruction.
+//!
+//! ```rust
+//! use fast_polynomial::{poly_array_t, arch::Fused};
+//!
+//! let y = poly_array_t(Fused(0.5f64), &[1.0, 0.3, 0.4, 1.6]).0;
+//! # assert!((y - 1.45).abs() < 1e-12);
+//! ```
+//!
+//! # Safety
+//!
+//! Executing an FMA instruction on a CPU without support for it is undefined behavior, so the
+//! intrinsics are only used when the instruction set is enabled _at compile time_:
+//!
+//! - On `x86_64`, the `fma` target feature must be enabled, such as via `-C target-feature=+fma`.
+//! - On `aarch64`, the `neon` target feature must be enabled, which it is by default.
+//!
+//! No runtime detection is performed. On any other configuration, [`Fused`] falls back to a
+//! separate multiply and add, which is _not_ fused and may round differently.
+//!
+//! Note that on `aarch64` with the `std` crate feature, this feature also allows the evaluation routines
+//! to call [`MulAdd`] for every type, as FMA is always available there and `std` inlines it as the instruction.
+//! Without `std`, that would be a call to `libm` at every step, so only [`Fused`] is affected.
+
+use core::ops::{Add, Div, Mul, Neg, Sub};
+use num_traits::{MulAdd, One, Zero};
+
+/// Transparent wrapper around `f32` or `f64` that uses hardware fused multiply-add.
+///
+/// See the [module-level documentation](self) for when the hardware instruction is used.
+#[repr(transparent)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Fused<T>(pub T);
+
+#[cfg(all(target_arch = "x86_64", target_feature = "fma"))]
+#[inline(always)]
+#[allow(unused_unsafe)]
+fn fma_f32(a: f32, b: f32, c: f32) -> f32 {
+    use core::arch::x86_64::*;
+
+    // SAFETY: the `fma` target feature is statically enabled
+    unsafe { _mm_cvtss_f32(_mm_fmadd_ss(_mm_set_ss(a), _mm_set_ss(b), _mm_set_ss(c))) }
+}
+
+#[cfg(all(target_arch = "x86_64", target_feature = "fma"))]
+#[inline(always)]
+#[allow(unused_unsafe)]
+fn fma_f64(a: f64, b: f64, c: f64) -> f64 {
+    use core::arch::x86_64::*;
+
+    // SAFETY: the `fma` target feature is statically enabled
+    unsafe { _mm_cvtsd_f64(_mm_fmadd_sd(_mm_set_sd(a), _mm_set_sd(b), _mm_set_sd(c))) }
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[inline(always)]
+#[allow(unused_unsafe)]
+fn fma_f32(a: f32, b: f32, c: f32) -> f32 {
+    use core::arch::aarch64::*;
+
+    // SAFETY: the `neon` target feature is statically enabled. Note `vfma` computes `c + a * b`
+    unsafe { vget_lane_f32::<0>(vfma_f32(vdup_n_f32(c), vdup_n_f32(a), vdup_n_f32(b))) }
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon"))]
+#[inline(always)]
+#[allow(unused_unsafe)]
+fn fma_f64(a: f64, b: f64, c: f64) -> f64 {
+    use core::arch::aarch64::*;
+
+    // SAFETY: the `neon` target feature is statically enabled. Note `vfma` computes `c + a * b`
+    unsafe { vget_lane_f64::<0>(vfma_f64(vdup_n_f64(c), vdup_n_f64(a), vdup_n_f64(b))) }
+}
+
+#[cfg(not(any(
+    all(target_arch = "x86_64", target_feature = "fma"),
+    all(target_arch = "aarch64", target_feature = "neon")
+)))]
+#[inline(always)]
+fn fma_f32(a: f32, b: f32, c: f32) -> f32 {
+    a * b + c
+}
+
+#[cfg(not(any(
+    all(target_arch = "x86_64", target_feature = "fma"),
+    all(target_arch = "aarch64", target_feature = "neon")
+)))]
+#[inline(always)]
+fn fma_f64(a: f64, b: f64, c: f64) -> f64 {
+    a * b + c
+}
+
+macro_rules! impl_fused {
+    ($($ty:ty => $fma:ident),*) => {$(
+        impl From<$ty> for Fused<$ty> {
+            #[inline(always)]
+            fn from(x: $ty) -> Self {
+                Fused(x)
+            }
+        }
+
+        impl MulAdd for Fused<$ty> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn mul_add(self, a: Self, b: Self) -> Self {
+                Fused($fma(self.0, a.0, b.0))
+            }
+        }
+
+        impl Zero for Fused<$ty> {
+            #[inline(always)]
+            fn zero() -> Self {
+                Fused(0.0)
+            }
+
+            #[inline(always)]
+            fn is_zero(&self) -> bool {
+                self.0 == 0.0
+            }
+        }
+
+        impl One for Fused<$ty> {
+            #[inline(always)]
+            fn one() -> Self {
+                Fused(1.0)
+            }
+        }
+
+        impl Neg for Fused<$ty> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn neg(self) -> Self {
+                Fused(-self.0)
+            }
+        }
+
+        impl_fused!(@binop $ty; Add add, Sub sub, Mul mul, Div div);
+    )*};
+
+    (@binop $ty:ty; $($op:ident $method:ident),*) => {$(
+        impl $op for Fused<$ty> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $method(self, rhs: Self) -> Self {
+                Fused(self.0.$method(rhs.0))
+            }
+        }
+    )*};
+}
+
+impl_fused!(f32 => fma_f32, f64 => fma_f64);
@@ -0,0 +1,191 @@
+//! Evaluation of polynomials represented in bases other than the monomial basis.
+//!
+//! The rest of this crate evaluates polynomials given as coefficients of `x^k`. However, polynomials
+//! often arise in other forms, such as from interpolation, where converting to monomial coefficients
+//! would be both wasteful and numerically unstable.
+
+use core::ops::{Div, Sub};
+use num_traits::One;
+
+use crate::{fma, PolyNum, PolyNumSigned};
+
+/// Compute the barycentric weights for the interpolation nodes `xs`, writing them into `weights`.
+///
+/// The weight for each node is `w_j = 1 / ∏(x_j - x_k)` for all `k != j`. These only depend on
+/// the nodes, so can be computed once and reused for any values passed to [`bary_eval`].
+///
+/// The nodes must be distinct, otherwise the weights will be infinite or `NaN`.
+///
+/// # Panics
+///
+/// Panics if `xs` and `weights` have different lengths.
+pub fn bary_weights<F>(xs: &[F], weights: &mut [F])
+where
+    F: PolyNumSigned + One + Div<Output = F>,
+{
+    assert_eq!(
+        xs.len(),
+        weights.len(),
+        "nodes and weights must have the same length"
+    );
+
+    for (j, w) in weights.iter_mut().enumerate() {
+        let xj = xs[j];
+        let mut prod = F::one();
+
+        for (k, &xk) in xs.iter().enumerate() {
+            if k != j {
+                prod = prod * (xj - xk);
+            }
+        }
+
+        *w = F::one() / prod;
+    }
+}
+
+/// Evaluate the polynomial interpolating the points `(xs[i], ys[i])` at `x`, using
+/// the barycentric formula with the given `weights` from [`bary_weights`].
+///
+/// This is the "second" or "true" form of barycentric interpolation:
+///
+/// ```text
+///        Σ w_j y_j / (x - x_j)
+/// p(x) = ---------------------
+///          Σ w_j / (x - x_j)
+/// ```
+///
+/// which is numerically stable and requires only `O(n)` operations per evaluation, without
+/// ever forming the monomial coefficients. If `x` is exactly equal to one of the nodes,
+/// the corresponding value is returned directly.
+///
+/// # Panics
+///
+/// Panics if `xs`, `ys` and `weights` do not all have the same length.
+pub fn bary_eval<F>(xs: &[F], ys: &[F], weights: &[F], x: F) -> F
+where
+    F: PolyNumSigned + Div<Output = F>,
+{
+    assert_eq!(
+        xs.len(),
+        ys.len(),
+        "nodes and values must have the same length"
+    );
+    assert_eq!(
+        xs.len(),
+        weights.len(),
+        "nodes and weights must have the same length"
+    );
+
+    let mut num = F::zero();
+    let mut den = F::zero();
+
+    for ((&xj, &yj), &wj) in xs.iter().zip(ys).zip(weights) {
+        let d = x - xj;
+
+        if d.is_zero() {
+            return yj;
+        }
+
+        let t = wj / d;
+
+        num = num + t * yj;
+        den = den + t;
+    }
+
+    num / den
+}
+
+/// Evaluate a polynomial in Newton form at `x`, given the coefficients and the nodes `x0, x1, ...`:
+///
+/// ```text
+/// p(x) = c0 + c1 (x - x0) + c2 (x - x0)(x - x1) + ... + cn (x - x0)...(x - x(n-1))
+/// ```
+///
+/// This is the form produced by interpolation with divided differences, and is evaluated with
+/// the nested recurrence `s = c_k + (x - x_k) s`, which is Horner's method with a shifted input
+/// at each step. Only the first `coeffs.len() - 1` nodes are used, so the nodes of an interpolation
+/// can be passed unchanged.
+///
+/// ```rust
+/// use fast_polynomial::bases::newton_eval;
+///
+/// // interpolates (0, 1), (1, 3), (2, 7), which is x^2 + x + 1
+/// let y = newton_eval(3.0f64, &[1.0, 2.0, 1.0], &[0.0, 1.0, 2.0]);
+///
+/// assert_eq!(y, 13.0);
+/// ```
+///
+/// # Panics
+///
+/// Panics if there are fewer than `coeffs.len() - 1` nodes.
+pub fn newton_eval<F>(x: F, coeffs: &[F], nodes: &[F]) -> F
+where
+    F: PolyNumSigned,
+{
+    let Some((&last, rest)) = coeffs.split_last() else {
+        return F::zero();
+    };
+
+    assert!(
+        nodes.len() >= rest.len(),
+        "expected at least {} nodes, found {}",
+        rest.len(),
+        nodes.len()
+    );
+
+    let mut sum = last;
+
+    for (&c, &xk) in rest.iter().zip(nodes).rev() {
+        sum = fma(x - xk, sum, c);
+    }
+
+    sum
+}
+
+/// Evaluate a polynomial in the falling factorial basis at `x`, given its coefficients:
+///
+/// ```text
+/// p(x) = c0 + c1 x + c2 x(x - 1) + ... + cn x(x - 1)...(x - n + 1)
+/// ```
+///
+/// This is the Newton form with the nodes `0, 1, 2, ...`, as arises from Newton's forward difference formula,
+/// where `c_k = Δ^k f(0) / k!`, so is evaluated with the same recurrence as [`newton_eval`]. The nodes are counted
+/// by repeatedly adding one, rather than by conversion, so this works for any number type, including unsigned
+/// integers when no `x - k` is negative, and each `x - k` is exact for integers and rounded only once for floats.
+///
+/// ```rust
+/// use fast_polynomial::bases::factorial_basis_eval;
+///
+/// // 1 + 2x + 3x(x - 1) at x = 4
+/// assert_eq!(factorial_basis_eval(4.0f64, &[1.0, 2.0, 3.0]), 45.0);
+///
+/// // x(x - 1)(x - 2) is zero at 0, 1 and 2
+/// assert_eq!(factorial_basis_eval(2i64, &[0, 0, 0, 1]), 0);
+/// ```
+pub fn factorial_basis_eval<F>(x: F, coeffs: &[F]) -> F
+where
+    F: PolyNum + Sub<Output = F> + One,
+{
+    let Some((&last, rest)) = coeffs.split_last() else {
+        return F::zero();
+    };
+
+    let one = F::one();
+
+    // one past the node of the innermost factor, `rest.len() - 1`,
+    // decremented before each use so it never goes below zero for unsigned types
+    let mut k = F::zero();
+
+    for _ in 0..rest.len() {
+        k = k + one;
+    }
+
+    let mut sum = last;
+
+    for &c in rest.iter().rev() {
+        k = k - one;
+        sum = fma(x - k, sum, c);
+    }
+
+    sum
+}
@@ -0,0 +1,113 @@
+//! Evaluation of polynomials at complex arguments.
+//!
+//! To avoid depending on any particular complex number type, complex numbers are given
+//! as `(re, im)` pairs of real numbers, which can be trivially converted to and from the
+//! types of other crates such as `num-complex`.
+
+use crate::{fma, PolyNumSigned};
+
+#[cfg(any(feature = "std", feature = "libm"))]
+use num_traits::Float;
+
+/// Evaluate a polynomial with real coefficients at a complex `x = (re, im)`, returning `(re, im)`.
+///
+/// Rather than promoting each coefficient to a complex number and performing full complex
+/// multiplications, this divides the polynomial by the real quadratic `(t - x)(t - x̄) = t² - 2 re(x) t + |x|²`
+/// with Goertzel's algorithm, such that only the remainder needs to be evaluated at `x`. This requires
+/// only two real multiply-adds per coefficient, compared to four for complex Horner's method.
+///
+/// This is useful for computing the frequency response of digital filters, which evaluates a
+/// transfer function with real coefficients on the unit circle:
+///
+/// ```rust
+/// use fast_polynomial::complex::poly_complex_x_real_coeff;
+///
+/// let omega = 0.3f64;
+/// let z = (omega.cos(), -omega.sin()); // e^-iω
+///
+/// // moving average filter, H(z) = (1 + z + z^2 + z^3) / 4
+/// let (re, im) = poly_complex_x_real_coeff(z, &[0.25, 0.25, 0.25, 0.25]);
+/// let gain = re.hypot(im);
+/// # assert!((gain - ((2.0 * omega).sin() / (4.0 * (omega / 2.0).sin())).abs()).abs() < 1e-12);
+/// ```
+pub fn poly_complex_x_real_coeff<F: PolyNumSigned>(x: (F, F), coeffs: &[F]) -> (F, F) {
+    let (re, im) = x;
+
+    let Some((&c0, rest)) = coeffs.split_first() else {
+        return (F::zero(), F::zero());
+    };
+
+    let r = re + re;
+    let s = fma(re, re, im * im);
+
+    // b1 and b2 are the coefficients of the running quotient
+    let (mut b1, mut b2) = (F::zero(), F::zero());
+
+    for &c in rest.iter().rev() {
+        let b0 = fma(r, b1, c) - s * b2;
+        b2 = b1;
+        b1 = b0;
+    }
+
+    // the remainder is b1 t + (c0 - s b2)
+    (fma(b1, re, c0) - s * b2, b1 * im)
+}
+
+/// Evaluate a polynomial with complex coefficients `(re, im)` at a complex `x = (re, im)`, returning `(re, im)`.
+///
+/// This is Horner's method with complex multiplication, requiring four real multiply-adds per coefficient.
+/// For real coefficients, [`poly_complex_x_real_coeff`] is roughly twice as fast.
+///
+/// ```rust
+/// use fast_polynomial::complex::poly_complex;
+///
+/// // (1 + i) + 2i x at x = i
+/// let (re, im) = poly_complex((0.0f64, 1.0), &[(1.0, 1.0), (0.0, 2.0)]);
+///
+/// assert_eq!((re, im), (-1.0, 1.0));
+/// ```
+pub fn poly_complex<F: PolyNumSigned>(x: (F, F), coeffs: &[(F, F)]) -> (F, F) {
+    let (re, im) = x;
+
+    let (mut sr, mut si) = (F::zero(), F::zero());
+
+    for &(cr, ci) in coeffs.iter().rev() {
+        (sr, si) = (fma(sr, re, cr) - si * im, fma(sr, im, fma(si, re, ci)));
+    }
+
+    (sr, si)
+}
+
+/// Evaluate a polynomial with complex coefficients at each of the `n = out.len()` complex roots of unity,
+/// such that `out[k] = p(ωᵏ)` where `ω = e^(2πi/n)`.
+///
+/// This is the evaluation step of polynomial multiplication by evaluation and interpolation. Each root
+/// is computed directly from its angle rather than by repeated multiplication, then evaluated with
+/// [`poly_complex`], for a total cost of `O(n · coeffs.len())`. This is intended as a simple and accurate
+/// reference; for large `n`, a fast Fourier transform computes the same values in `O(n log n)`,
+/// as `out[k]` is the `k`-th element of the inverse DFT of the coefficients, scaled by `n`.
+///
+/// Requires either the `std` or `libm` crate features.
+///
+/// ```rust
+/// use fast_polynomial::complex::eval_at_roots_of_unity;
+///
+/// // 1 + x², which is zero at ±i
+/// let mut out = [(0.0f64, 0.0); 4];
+/// eval_at_roots_of_unity(&[(1.0, 0.0), (0.0, 0.0), (1.0, 0.0)], &mut out);
+///
+/// for ((re, im), expected) in out.into_iter().zip([2.0, 0.0, 2.0, 0.0]) {
+///     assert!((re - expected).abs() < 1e-15 && im.abs() < 1e-15);
+/// }
+/// ```
+#[cfg(any(feature = "std", feature = "libm"))]
+pub fn eval_at_roots_of_unity<F: Float + PolyNumSigned>(coeffs: &[(F, F)], out: &mut [(F, F)]) {
+    let n = F::from(out.len()).unwrap();
+    let tau = F::from(core::f64::consts::TAU).unwrap();
+
+    for (k, y) in out.iter_mut().enumerate() {
+        let (s, c) = (tau * (F::from(k).unwrap() / n)).sin_cos();
+
+        *y = poly_complex((c, s), coeffs);
+    }
+}
@@ -0,0 +1,129 @@
+//! Diagnostic evaluation routines for understanding the behavior of polynomial approximations.
+//!
+//! These compute additional information alongside the value of the polynomial, and are
+//! intended for debugging or for deciding when a more accurate evaluation method is required.
+
+use num_traits::One;
+
+use crate::{abs, poly_f_n, PolyNumSigned};
+
+/// Evaluate a polynomial for an array of coefficients, alongside the sum of the absolute
+/// values of each term, `Σ|c_k x^k|`. Can be monomorphized.
+///
+/// The condition number of the evaluation is then `cond = Σ|c_k x^k| / |p(x)|`, where large
+/// values indicate significant cancellation between terms, and that the result may be inaccurate.
+/// Without any cancellation, `cond` will be exactly 1.
+///
+/// The sum of absolute terms is evaluated as a second polynomial, `Σ|c_k| |x|^k`, so this
+/// is roughly twice as expensive as [`poly_array`](crate::poly_array).
+///
+/// ```rust
+/// use fast_polynomial::diagnostics::poly_array_cond;
+///
+/// let (y, abs_sum) = poly_array_cond(0.5, &[1.0, -2.0, 1.0]);
+///
+/// assert_eq!(y, 0.25);
+/// assert_eq!(abs_sum, 2.25);
+/// ```
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+pub fn poly_array_cond<F, const N: usize>(x: F, coeffs: &[F; N]) -> (F, F)
+where
+    F: PolyNumSigned + PartialOrd,
+{
+    let value = poly_f_n::<F, _, N>(x, |i| unsafe { *coeffs.get_unchecked(i) });
+    let abs_sum = poly_f_n::<F, _, N>(abs(x), |i| unsafe { abs(*coeffs.get_unchecked(i)) });
+
+    (value, abs_sum)
+}
+
+/// Evaluate a polynomial for an array of coefficients, alongside the index `k` of the term
+/// with the largest magnitude `|c_k x^k|`. Can be monomorphized.
+///
+/// This is useful for understanding whether the low- or high-order terms dominate the result
+/// in a problematic region of an approximation. If several terms have the same magnitude,
+/// the lowest index is returned. Terms that cannot be compared, such as NaN, are skipped.
+/// If there are no coefficients, the index is zero.
+///
+/// The magnitudes are computed with a running power of `|x|`, so are each
+/// accurate to within a few ULPs, rather than the combined sum of [`poly_array_cond`].
+///
+/// ```rust
+/// use fast_polynomial::diagnostics::poly_array_dominant;
+///
+/// let (y, k) = poly_array_dominant(3.0, &[1.0, -2.0, 1.0]);
+///
+/// assert_eq!(y, 4.0);
+/// assert_eq!(k, 2);
+/// ```
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+pub fn poly_array_dominant<F, const N: usize>(x: F, coeffs: &[F; N]) -> (F, usize)
+where
+    F: PolyNumSigned + One + PartialOrd,
+{
+    let value = poly_f_n::<F, _, N>(x, |i| unsafe { *coeffs.get_unchecked(i) });
+
+    let ax = abs(x);
+    let mut xk = F::one();
+
+    let mut max = F::zero();
+    let mut dominant = 0;
+
+    for (k, &c) in coeffs.iter().enumerate() {
+        let term = abs(c) * xk;
+
+        if term > max {
+            max = term;
+            dominant = k;
+        }
+
+        xk = xk * ax;
+    }
+
+    (value, dominant)
+}
+
+/// Evaluate a polynomial with Horner's method, alongside the number of times its running sum changes sign.
+///
+/// The running sums `s_(n-1) = c_(n-1)` and `s_k = s_(k+1) x + c_k` are the coefficients of the quotient of the
+/// polynomial by `(y - x)` from synthetic division, followed by the remainder `p(x)`. Sums that are zero or NaN
+/// have no sign, so are skipped, with changes counted between the signs of the sums on either side of them.
+///
+/// As a heuristic for root-finding, a change in sign in the last step is a change in sign between the quotient's constant
+/// term and `p(x)`, and the total count can be compared between the endpoints of an interval. It makes no guarantees about
+/// the presence or absence of roots, and should only be used to guide a reliable method such as bisection.
+///
+/// The value is computed with separate multiplications and additions, so may differ from [`poly`](crate::poly)
+/// by rounding.
+///
+/// ```rust
+/// use fast_polynomial::diagnostics::poly_sign_changes;
+///
+/// // sums 1, 1 * 2 - 3 = -1, -1 * 2 + 2 = 0
+/// assert_eq!(poly_sign_changes(2.0, &[2.0, -3.0, 1.0]), (0.0, 1));
+///
+/// // sums 1, 1 * 3 - 3 = 0, 0 * 3 + 2 = 2, with the zero skipped
+/// assert_eq!(poly_sign_changes(3.0, &[2.0, -3.0, 1.0]), (2.0, 0));
+/// ```
+pub fn poly_sign_changes(x: f64, coeffs: &[f64]) -> (f64, u32) {
+    let mut sum = 0.0;
+    let mut changes = 0;
+    let mut last_negative = None;
+
+    for &c in coeffs.iter().rev() {
+        sum = sum * x + c;
+
+        if sum != 0.0 && !sum.is_nan() {
+            let negative = sum < 0.0;
+
+            if last_negative.is_some_and(|last| last != negative) {
+                changes += 1;
+            }
+
+            last_negative = Some(negative);
+        }
+    }
+
+    (sum, changes)
+}
@@ -0,0 +1,192 @@
+//! Runtime selection of fused multiply-add on `x86_64`.
+//!
+//! The evaluation routines of this crate only use fused multiply-add if the `fma` target feature is enabled
+//! at compile-time, which prevents a binary built for baseline `x86_64` from using it on CPUs that support it.
+//! [`poly_array_dispatch`] instead contains a copy of the evaluation compiled with `#[target_feature]`, which is
+//! called only after detecting support for AVX2 and FMA at runtime. Detection is performed once and cached.
+//!
+//! The copy follows the same scheme as [`poly_array`](crate::poly_array), differing only in that every multiply-add
+//! is fused, but as fused multiply-add rounds differently, results may still differ between CPUs running the same binary.
+//! Where that matters, prefer the [`fused`](crate::fused) module or the `deterministic` crate feature.
+//!
+//! On any other architecture, if the `fma` target feature is already enabled at compile-time,
+//! or with the `deterministic` crate feature, this is simply [`poly_array`](crate::poly_array).
+
+use num_traits::Float;
+
+use crate::PolyNum;
+
+#[cfg(all(
+    target_arch = "x86_64",
+    not(target_feature = "fma"),
+    not(feature = "deterministic")
+))]
+mod detect {
+    use core::sync::atomic::{AtomicU8, Ordering};
+
+    const UNKNOWN: u8 = 0;
+    const BASELINE: u8 = 1;
+    const FMA: u8 = 2;
+
+    static LEVEL: AtomicU8 = AtomicU8::new(UNKNOWN);
+
+    #[inline]
+    pub fn has_fma() -> bool {
+        match LEVEL.load(Ordering::Relaxed) {
+            UNKNOWN => {
+                let fma =
+                    std::is_x86_feature_detected!("fma") && std::is_x86_feature_detected!("avx2");
+
+                // races are benign, as every thread will detect the same result
+                LEVEL.store(if fma { FMA } else { BASELINE }, Ordering::Relaxed);
+
+                fma
+            }
+            level => level == FMA,
+        }
+    }
+}
+
+#[cfg(all(
+    target_arch = "x86_64",
+    not(target_feature = "fma"),
+    not(feature = "deterministic")
+))]
+mod contracted {
+    use core::ops::{Add, Mul};
+
+    use num_traits::{Float, MulAdd, Zero};
+
+    use crate::PolyNum;
+
+    /// [`poly_array`](crate::poly_array) compiled with fused multiply-add enabled.
+    #[target_feature(enable = "avx2,fma")]
+    pub unsafe fn poly_array<F: PolyNum + Float, const N: usize>(x: F, coeffs: &[F; N]) -> F {
+        crate::poly_f_n::<_, _, N>(Value(x), |i| Value(*coeffs.get_unchecked(i))).get()
+    }
+
+    /// Value within [`poly_array`], deferring each product until it's known whether it's added to.
+    ///
+    /// Without the `fma` target feature at compile-time, the evaluation routines multiply-add as `x * m + a`,
+    /// which becomes a single [`Float::mul_add`] here, and so the hardware instruction within [`poly_array`].
+    /// Products used in any other way are rounded as usual, and sums are only fused when the product is on the left,
+    /// as the evaluation routines never fuse `a + x * m` with the `fma` target feature enabled either.
+    #[derive(Clone, Copy)]
+    enum Deferred<F> {
+        Value(F),
+        Product(F, F),
+    }
+
+    use Deferred::{Product, Value};
+
+    impl<F: Float> Deferred<F> {
+        #[inline(always)]
+        fn get(self) -> F {
+            match self {
+                Value(v) => v,
+                Product(a, b) => a * b,
+            }
+        }
+    }
+
+    impl<F: Float> Mul for Deferred<F> {
+        type Output = Self;
+
+        #[inline(always)]
+        fn mul(self, rhs: Self) -> Self {
+            Product(self.get(), rhs.get())
+        }
+    }
+
+    impl<F: Float> Add for Deferred<F> {
+        type Output = Self;
+
+        #[inline(always)]
+        fn add(self, rhs: Self) -> Self {
+            match self {
+                Product(a, b) => Value(a.mul_add(b, rhs.get())),
+                Value(v) => Value(v + rhs.get()),
+            }
+        }
+    }
+
+    impl<F: Float> MulAdd for Deferred<F> {
+        type Output = Self;
+
+        #[inline(always)]
+        fn mul_add(self, m: Self, a: Self) -> Self {
+            Value(self.get().mul_add(m.get(), a.get()))
+        }
+    }
+
+    impl<F: Float> Zero for Deferred<F> {
+        #[inline(always)]
+        fn zero() -> Self {
+            Value(F::zero())
+        }
+
+        #[inline(always)]
+        fn is_zero(&self) -> bool {
+            self.get().is_zero()
+        }
+    }
+}
+
+/// Returns true if [`poly_array_dispatch`] uses fused multiply-add on this CPU.
+#[inline]
+pub fn fma_detected() -> bool {
+    #[cfg(all(
+        target_arch = "x86_64",
+        not(target_feature = "fma"),
+        not(feature = "deterministic")
+    ))]
+    return detect::has_fma();
+
+    #[cfg(not(all(
+        target_arch = "x86_64",
+        not(target_feature = "fma"),
+        not(feature = "deterministic")
+    )))]
+    return cfg!(all(
+        not(feature = "deterministic"),
+        any(
+            target_feature = "fma",
+            all(
+                feature = "arch-fma",
+                feature = "std",
+                target_arch = "aarch64",
+                target_feature = "neon"
+            )
+        )
+    ));
+}
+
+/// Evaluate a polynomial for an array of coefficients, using fused multiply-add if the CPU supports it.
+/// Can be monomorphized.
+///
+/// When selected at runtime, the polynomial is evaluated with the same scheme as [`poly_array`](crate::poly_array),
+/// with every multiply-add fused, so the result is identical to [`poly_array`](crate::poly_array) compiled with
+/// the `fma` target feature enabled.
+///
+/// See the [module-level documentation](self) for details.
+///
+/// ```rust
+/// use fast_polynomial::dispatch::poly_array_dispatch;
+///
+/// let y = poly_array_dispatch(0.5f64, &[1.0, 0.3, 0.4, 1.6]);
+/// # assert!((y - 1.45).abs() < 1e-12);
+/// ```
+#[inline]
+pub fn poly_array_dispatch<F: PolyNum + Float, const N: usize>(x: F, coeffs: &[F; N]) -> F {
+    #[cfg(all(
+        target_arch = "x86_64",
+        not(target_feature = "fma"),
+        not(feature = "deterministic")
+    ))]
+    if detect::has_fma() {
+        // SAFETY: support for the target features was detected above
+        return unsafe { contracted::poly_array(x, coeffs) };
+    }
+
+    crate::poly_array(x, coeffs)
+}
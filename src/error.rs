@@ -0,0 +1,43 @@
+//! Errors returned by the fallible evaluation routines.
+
+use core::fmt;
+
+/// The reason a fallible evaluation routine failed.
+///
+/// This is shared between all fallible routines of this crate, and more variants may be added
+/// as more routines are added.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PolyError {
+    /// The denominator of a rational polynomial has no coefficients.
+    EmptyDenominator,
+
+    /// An integer evaluation overflowed.
+    Overflow,
+
+    /// The result of the evaluation was infinite or NaN.
+    NonFinite,
+
+    /// The number of coefficients did not match the expected length.
+    LengthMismatch {
+        /// The expected number of coefficients.
+        expected: usize,
+        /// The number of coefficients given.
+        found: usize,
+    },
+}
+
+impl fmt::Display for PolyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            PolyError::EmptyDenominator => f.write_str("denominator has no coefficients"),
+            PolyError::Overflow => f.write_str("integer overflow during evaluation"),
+            PolyError::NonFinite => f.write_str("result is not finite"),
+            PolyError::LengthMismatch { expected, found } => {
+                write!(f, "expected {expected} coefficients, found {found}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for PolyError {}
@@ -0,0 +1,97 @@
+//! Evaluation with an explicitly chosen fused multiply-add implementation.
+//!
+//! The rest of this crate uses fused multiply-add only when the target supports it at compile-time,
+//! and otherwise falls back to a separate multiply and add, so results can differ between targets.
+//! Furthermore, the `std` and `libm` crate features select the implementation of [`MulAdd`](num_traits::MulAdd)
+//! for the whole program.
+//!
+//! The functions in this module instead always fuse every multiply-add with the implementation
+//! chosen at the call site by a marker type, either [`Std`] or `Libm`. As fused multiply-add
+//! is correctly rounded, both produce bit-identical results on every target, evaluated with Horner's method.
+//! They differ only in speed: [`Std`] is usually inlined as the hardware instruction when the target enables it,
+//! while `Libm` is an out-of-line call, independent of the standard library, which selects the hardware
+//! instruction at runtime on x86_64 and uses it directly on aarch64, falling back to a software implementation.
+//!
+//! ```rust
+//! # #[cfg(feature = "std")] {
+//! use fast_polynomial::fused::{poly_array_fused, Std};
+//!
+//! let y = poly_array_fused::<Std, f64, 4>(0.5, &[1.0, 0.3, 0.4, 1.6]);
+//! # assert!((y - 1.45).abs() < 1e-12);
+//! # }
+//! ```
+
+/// Marker type selecting the fused multiply-add of the standard library, such as [`f64::mul_add`].
+///
+/// Requires the `std` crate feature.
+#[cfg(feature = "std")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Std;
+
+/// Marker type selecting the fused multiply-add of the `libm` crate.
+///
+/// Requires the `libm` crate feature.
+#[cfg(feature = "libm")]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Libm;
+
+/// Types with a fused multiply-add provided by the backend `B`.
+pub trait FusedMulAdd<B>: Copy {
+    /// The additive identity.
+    const ZERO: Self;
+
+    /// Compute `(self * m) + a` with a single rounding.
+    fn fused_mul_add(self, m: Self, a: Self) -> Self;
+}
+
+macro_rules! impl_fused {
+    ($backend:ty, $cfg:literal: $($ty:ty => $f:path),*) => {$(
+        #[cfg(feature = $cfg)]
+        impl FusedMulAdd<$backend> for $ty {
+            const ZERO: Self = 0.0;
+
+            #[inline(always)]
+            fn fused_mul_add(self, m: Self, a: Self) -> Self {
+                $f(self, m, a)
+            }
+        }
+    )*};
+}
+
+impl_fused!(Std, "std": f32 => f32::mul_add, f64 => f64::mul_add);
+impl_fused!(Libm, "libm": f32 => libm::fmaf, f64 => libm::fma);
+
+/// Evaluate a polynomial for a slice of coefficients with Horner's method, fusing every multiply-add
+/// with the backend `B`.
+#[inline]
+pub fn poly_fused<B, F: FusedMulAdd<B>>(x: F, coeffs: &[F]) -> F {
+    coeffs
+        .iter()
+        .rev()
+        .fold(F::ZERO, |sum, &c| sum.fused_mul_add(x, c))
+}
+
+/// Evaluate a polynomial for an array of coefficients with Horner's method, fusing every multiply-add
+/// with the backend `B`. Can be monomorphized.
+#[inline]
+pub fn poly_array_fused<B, F: FusedMulAdd<B>, const N: usize>(x: F, coeffs: &[F; N]) -> F {
+    poly_fused::<B, F>(x, coeffs)
+}
+
+/// [`poly_array_fused`] with the [`Std`] backend.
+///
+/// Requires the `std` crate feature.
+#[cfg(feature = "std")]
+#[inline]
+pub fn poly_array_std<F: FusedMulAdd<Std>, const N: usize>(x: F, coeffs: &[F; N]) -> F {
+    poly_array_fused::<Std, F, N>(x, coeffs)
+}
+
+/// [`poly_array_fused`] with the [`Libm`] backend.
+///
+/// Requires the `libm` crate feature.
+#[cfg(feature = "libm")]
+#[inline]
+pub fn poly_array_libm<F: FusedMulAdd<Libm>, const N: usize>(x: F, coeffs: &[F; N]) -> F {
+    poly_array_fused::<Libm, F, N>(x, coeffs)
+}
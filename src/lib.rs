@@ -1,7 +1,13 @@
 #![no_std]
 #![doc = include_str!("../README.md")]
 
-use core::ops::{Add, Div, Mul, Neg};
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "std")]
+extern crate std;
+
+use core::ops::{Add, Div, Mul, Neg, Sub};
 use num_traits::{MulAdd, One, Zero};
 
 /// The minimum required functionality for a number to evaluated in a polynomial. [`MulAdd`]
@@ -33,7 +39,16 @@ pub trait PolyNum:
 /// [`One`] and [`PartialOrd`] are required to perform a specific optimization for rational
 /// polynomials wherein the input is inverted if the absolute value of the input is greater than 1.
 /// This is useful for numerical stability, as it keeps the powers of the input within the range of 0 to 1.
-pub trait PolyRational: PolyNum + One + Div<Self, Output = Self> + PartialOrd {}
+pub trait PolyRational:
+    PolyNum + One + Neg<Output = Self> + Div<Self, Output = Self> + PartialOrd
+{
+}
+
+/// Extension of [`PolyNum`] for numbers that support subtraction and negation.
+///
+/// Schemes such as Clenshaw's algorithm, synthetic division or interpolation require subtraction,
+/// and use this trait rather than adding [`Sub`] and [`Neg`] bounds individually.
+pub trait PolyNumSigned: PolyNum + Sub<Self, Output = Self> + Neg<Output = Self> {}
 
 impl<T> PolyNum for T where
     T: Sized
@@ -45,24 +60,385 @@ impl<T> PolyNum for T where
 {
 }
 
+impl<T> PolyNumSigned for T where T: PolyNum + Sub<Self, Output = Self> + Neg<Output = Self> {}
+
 impl<T> PolyRational for T where
     T: PolyNum + One + Neg<Output = Self> + Div<Self, Output = Self> + PartialOrd
 {
 }
 
+/// Random-access source of polynomial coefficients, in order of increasing degree.
+///
+/// This allows any container of coefficients to be passed directly to [`poly_coeffs`] and
+/// [`rational_coeffs`], rather than choosing between the slice, array and closure-based entry points.
+/// Implemented for slices, arrays and `Vec` (with the `alloc` feature), as well as references to them.
+/// Other containers, such as vectors from linear algebra crates, can implement it themselves.
+pub trait Coefficients<F> {
+    /// The number of coefficients known at compile-time, or zero if only known at runtime.
+    ///
+    /// When non-zero and equal to [`len`](Coefficients::len), this allows the evaluation
+    /// to be monomorphized in the same way as [`poly_array`]. Otherwise, it is ignored.
+    const LEN: usize = 0;
+
+    /// The number of coefficients.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if there are no coefficients.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The coefficient of `x^i`.
+    ///
+    /// May panic if `i >= self.len()`.
+    fn coeff(&self, i: usize) -> F;
+
+    /// The coefficient of `x^i`, without bounds checking.
+    ///
+    /// The default implementation calls [`coeff`](Coefficients::coeff).
+    ///
+    /// # Safety
+    ///
+    /// `i` must be less than `self.len()`.
+    #[inline(always)]
+    unsafe fn coeff_unchecked(&self, i: usize) -> F {
+        self.coeff(i)
+    }
+}
+
+impl<F: Copy> Coefficients<F> for [F] {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        <[F]>::len(self)
+    }
+
+    #[inline(always)]
+    fn coeff(&self, i: usize) -> F {
+        self[i]
+    }
+
+    #[inline(always)]
+    unsafe fn coeff_unchecked(&self, i: usize) -> F {
+        *<[F]>::get_unchecked(self, i)
+    }
+}
+
+impl<F: Copy, const N: usize> Coefficients<F> for [F; N] {
+    const LEN: usize = N;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        N
+    }
+
+    #[inline(always)]
+    fn coeff(&self, i: usize) -> F {
+        self[i]
+    }
+
+    #[inline(always)]
+    unsafe fn coeff_unchecked(&self, i: usize) -> F {
+        *<[F]>::get_unchecked(self, i)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<F: Copy> Coefficients<F> for alloc::vec::Vec<F> {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        <[F]>::len(self)
+    }
+
+    #[inline(always)]
+    fn coeff(&self, i: usize) -> F {
+        self[i]
+    }
+
+    #[inline(always)]
+    unsafe fn coeff_unchecked(&self, i: usize) -> F {
+        *<[F]>::get_unchecked(self, i)
+    }
+}
+
+impl<F, C: Coefficients<F> + ?Sized> Coefficients<F> for &C {
+    const LEN: usize = C::LEN;
+
+    #[inline(always)]
+    fn len(&self) -> usize {
+        C::len(self)
+    }
+
+    #[inline(always)]
+    fn coeff(&self, i: usize) -> F {
+        C::coeff(self, i)
+    }
+
+    #[inline(always)]
+    unsafe fn coeff_unchecked(&self, i: usize) -> F {
+        C::coeff_unchecked(self, i)
+    }
+}
+
+/// A polynomial that can be evaluated at `x`.
+///
+/// This allows code to be generic over anything that can be evaluated, without choosing between
+/// [`poly`] and [`poly_array`]. It is implemented for every container of [`Coefficients`] through
+/// [`poly_coeffs`], so arrays are monomorphized as with [`poly_array`], while slices and `Vec` use
+/// the same dispatch as [`poly`]. Other types, such as piecewise or otherwise structured polynomials,
+/// can implement it themselves.
+///
+/// ```rust
+/// use fast_polynomial::Evaluate;
+///
+/// fn integrate<P: Evaluate<f64>>(p: P, a: f64, b: f64, steps: usize) -> f64 {
+///     let h = (b - a) / steps as f64;
+///     (0..steps).map(|i| p.eval(a + (i as f64 + 0.5) * h) * h).sum()
+/// }
+///
+/// let c = [0.0, 0.0, 3.0]; // 3x^2
+///
+/// assert!((integrate(c, 0.0, 1.0, 1000) - 1.0).abs() < 1e-6);
+/// assert!((integrate(&c[..], 0.0, 1.0, 1000) - 1.0).abs() < 1e-6);
+/// ```
+pub trait Evaluate<F> {
+    /// Evaluate the polynomial at `x`.
+    fn eval(&self, x: F) -> F;
+}
+
+impl<F: PolyNum, C: Coefficients<F> + ?Sized> Evaluate<F> for C {
+    #[inline(always)]
+    fn eval(&self, x: F) -> F {
+        poly_coeffs(x, self)
+    }
+}
+
+/// Object-safe source of polynomial coefficients, in order of increasing degree, for use with [`poly_dyn`].
+///
+/// [`Coefficients`] has an associated constant for monomorphization, so it cannot be used as a trait object.
+/// This provides the same random access through `dyn CoeffSource<F>`, such that coefficients can be supplied
+/// by providers only known at runtime, such as plugins. It is implemented for every container of [`Coefficients`].
+pub trait CoeffSource<F = f64> {
+    /// The number of coefficients.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if there are no coefficients.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The coefficient of `x^i`.
+    ///
+    /// May panic if `i >= self.len()`.
+    fn coeff(&self, i: usize) -> F;
+}
+
+impl<F, C: Coefficients<F> + ?Sized> CoeffSource<F> for C {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        Coefficients::len(self)
+    }
+
+    #[inline(always)]
+    fn coeff(&self, i: usize) -> F {
+        Coefficients::coeff(self, i)
+    }
+}
+
 #[inline(always)]
 fn fma<F>(x: F, m: F, a: F) -> F
 where
     F: MulAdd<F, F, Output = F> + Add<F, Output = F> + Mul<F, Output = F>,
 {
-    #[cfg(target_feature = "fma")]
+    #[cfg(all(
+        not(feature = "deterministic"),
+        any(
+            target_feature = "fma",
+            all(
+                feature = "arch-fma",
+                feature = "std",
+                target_arch = "aarch64",
+                target_feature = "neon"
+            )
+        )
+    ))]
     return x.mul_add(m, a);
 
-    #[cfg(not(target_feature = "fma"))]
+    #[cfg(not(all(
+        not(feature = "deterministic"),
+        any(
+            target_feature = "fma",
+            all(
+                feature = "arch-fma",
+                feature = "std",
+                target_arch = "aarch64",
+                target_feature = "neon"
+            )
+        )
+    )))]
     return x * m + a;
 }
 
+/// `n` as `F`, built from [`One`] by doubling and adding, for types without a conversion from integers.
+///
+/// Exact as long as every integer up to `n` is representable in `F`.
+#[inline(always)]
+fn from_usize<F: PolyNum + One>(n: usize) -> F {
+    let mut r = F::zero();
+
+    for bit in (0..usize::BITS - n.leading_zeros()).rev() {
+        r = r + r;
+
+        if (n >> bit) & 1 != 0 {
+            r = r + F::one();
+        }
+    }
+
+    r
+}
+
+#[inline(always)]
+fn abs<F: PolyNumSigned + PartialOrd>(x: F) -> F {
+    if x < F::zero() {
+        -x
+    } else {
+        x
+    }
+}
+
+/// The operations needed to evaluate with [`NoContract`], which unlike [`PolyNum`] excludes [`MulAdd`].
+trait Unfused: Copy + Zero + Add<Output = Self> + Mul<Output = Self> {}
+
+impl<T: Copy + Zero + Add<Output = T> + Mul<Output = T>> Unfused for T {}
+
+/// Wrapper whose [`MulAdd`] is always a separately-rounded multiply and add, used by [`poly_array_no_contract`].
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+struct NoContract<F>(F);
+
+impl<F: Unfused> Add for NoContract<F> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self {
+        NoContract(self.0 + rhs.0)
+    }
+}
+
+impl<F: Unfused> Mul for NoContract<F> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self {
+        NoContract(self.0 * rhs.0)
+    }
+}
+
+impl<F: Unfused> MulAdd for NoContract<F> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul_add(self, m: Self, a: Self) -> Self {
+        NoContract(self.0 * m.0 + a.0)
+    }
+}
+
+impl<F: Unfused> Zero for NoContract<F> {
+    #[inline(always)]
+    fn zero() -> Self {
+        NoContract(F::zero())
+    }
+
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+/// Dual number `v + d ε` with `ε² = 0`, used by [`poly_array_d`] to carry derivatives through the evaluation.
+#[derive(Clone, Copy)]
+struct Dual<F> {
+    v: F,
+    d: F,
+}
+
+impl<F: PolyNum> Add for Dual<F> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn add(self, rhs: Self) -> Self {
+        Dual {
+            v: self.v + rhs.v,
+            d: self.d + rhs.d,
+        }
+    }
+}
+
+impl<F: PolyNum> Mul for Dual<F> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul(self, rhs: Self) -> Self {
+        Dual {
+            v: self.v * rhs.v,
+            d: fma(self.d, rhs.v, self.v * rhs.d),
+        }
+    }
+}
+
+impl<F: PolyNum> MulAdd for Dual<F> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul_add(self, m: Self, a: Self) -> Self {
+        Dual {
+            v: fma(self.v, m.v, a.v),
+            d: fma(self.d, m.v, fma(self.v, m.d, a.d)),
+        }
+    }
+}
+
+impl<F: PolyNum> Zero for Dual<F> {
+    #[inline(always)]
+    fn zero() -> Self {
+        Dual {
+            v: F::zero(),
+            d: F::zero(),
+        }
+    }
+
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.v.is_zero() && self.d.is_zero()
+    }
+}
+
+pub mod accurate;
+pub mod algebra;
+#[cfg(feature = "arch-fma")]
+pub mod arch;
+pub mod bases;
+pub mod complex;
+pub mod diagnostics;
+#[cfg(feature = "runtime-detection")]
+pub mod dispatch;
+mod error;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub mod fused;
+pub mod many_xs;
+#[cfg(feature = "std")]
+pub mod memo;
 pub mod polynomials;
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+pub mod preprocessed;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub mod roots;
+#[cfg(feature = "rounding")]
+pub mod rounding;
+pub mod streaming;
+
+pub use error::PolyError;
 
 /// Evaluate a polynomial for an array of coefficients. Can be monomorphized.
 ///
@@ -70,18 +446,58 @@ pub mod polynomials;
 /// the array of this length, removing many/all branches within the internal code that
 /// other methods such as [`poly`] may require to support many lengths. This function will
 /// be faster, put simply.
-#[inline(always)]
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
 pub fn poly_array<F: PolyNum, const N: usize>(x: F, coeffs: &[F; N]) -> F {
     poly_f_n::<F, _, N>(x, |i| unsafe { *coeffs.get_unchecked(i) })
 }
 
+/// Evaluate a polynomial for an array of coefficients, only monomorphizing for up to `MAX_UNROLL` coefficients.
+///
+/// Each distinct `N` passed to [`poly_array`] generates its own unrolled code, which can add up in generic code
+/// instantiated for many lengths. With `N <= MAX_UNROLL`, this is identical to [`poly_array`], while longer arrays
+/// are passed as slices to a single out-of-line instance of [`poly`] per type `F`, shared between all such `N`,
+/// bounding the code size at the cost of dispatching on the length at runtime. The results are identical either way.
+///
+/// ```rust
+/// use fast_polynomial::{poly_array, poly_array_capped};
+///
+/// let c4 = [1.0f64, 0.3, 0.4, 1.6];
+/// let c20 = [0.5f64; 20];
+///
+/// // unrolled as with poly_array
+/// assert_eq!(poly_array_capped::<_, 4, 8>(0.5, &c4), poly_array(0.5, &c4));
+///
+/// // evaluated out-of-line
+/// assert_eq!(poly_array_capped::<_, 20, 8>(0.5, &c20), poly_array(0.5, &c20));
+/// ```
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+pub fn poly_array_capped<F: PolyNum, const N: usize, const MAX_UNROLL: usize>(
+    x: F,
+    coeffs: &[F; N],
+) -> F {
+    if N <= MAX_UNROLL {
+        poly_array(x, coeffs)
+    } else {
+        poly_outlined(x, coeffs)
+    }
+}
+
+/// [`poly`] that is never inlined, so that a single instance is shared between all lengths.
+#[inline(never)]
+fn poly_outlined<F: PolyNum>(x: F, coeffs: &[F]) -> F {
+    poly(x, coeffs)
+}
+
 /// Evaluate a rational polynomial for an array of coefficients. Can be monomorphized.
 ///
 /// To be monomorphized means a dedicated instance of this code will be generated for
 /// the array of this length, removing many/all branches within the internal code that
 /// other methods such as [`rational`] may require to support many lengths. This function will
 /// be faster, put simply.
-#[inline(always)]
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
 pub fn rational_array<F: PolyRational, const P: usize, const Q: usize>(
     x: F,
     numerator: &[F; P],
@@ -94,52 +510,1291 @@ pub fn rational_array<F: PolyRational, const P: usize, const Q: usize>(
     )
 }
 
-/// More flexible variant of [`poly_array`]
-#[inline(always)]
-pub fn poly_array_t<F: PolyNum, T, const N: usize>(x: F, coeffs: &[T; N]) -> F
-where
-    T: Clone + Into<F>,
-{
-    poly_f_n::<F, _, N>(x, |i| unsafe { coeffs.get_unchecked(i).clone().into() })
+/// The powers of `x` used by Estrin's scheme, `x`, `x²`, `x⁴`, `x⁸` and `x¹⁶`.
+///
+/// Returned by [`poly_array_with_powers`], and can be passed to [`poly_array_powers`] to evaluate
+/// further polynomials at the same `x` without recomputing them, or used directly with the fixed-degree
+/// functions of the [`polynomials`] module.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Powers<F> {
+    pub x: F,
+    pub x2: F,
+    pub x4: F,
+    pub x8: F,
+    pub x16: F,
+}
+
+impl<F: PolyNum> Powers<F> {
+    /// Compute the powers of `x`, using 4 multiplications.
+    #[inline(always)]
+    pub fn new(x: F) -> Self {
+        let x2 = x * x;
+        let x4 = x2 * x2;
+        let x8 = x4 * x4;
+
+        Powers {
+            x,
+            x2,
+            x4,
+            x8,
+            x16: x8 * x8,
+        }
+    }
+}
+
+/// Evaluate a polynomial and its first derivative for an array of coefficients, returning `(p(x), p'(x))`.
+/// Can be monomorphized.
+///
+/// Rather than building the coefficients of the derivative, the derivative of every sub-expression of
+/// Estrin's scheme is tracked alongside its value, including the powers of `x`, such that the powers are
+/// shared between both. The value is identical to [`poly_array`]. This is useful for the inner loop of
+/// Newton's method, where only the value and slope are needed:
+///
+/// ```rust
+/// use fast_polynomial::poly_array_d;
+///
+/// // x^2 - 2, starting from 1.5
+/// let mut x = 1.5f64;
+/// for _ in 0..5 {
+///     let (y, dy) = poly_array_d(x, &[-2.0, 0.0, 1.0]);
+///     x -= y / dy;
+/// }
+///
+/// assert!((x - 2f64.sqrt()).abs() < 1e-15);
+/// ```
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+pub fn poly_array_d<F: PolyNum + One, const N: usize>(x: F, coeffs: &[F; N]) -> (F, F) {
+    let x = Dual { v: x, d: F::one() };
+
+    let y = poly_f_n::<Dual<F>, _, N>(x, |i| Dual {
+        v: unsafe { *coeffs.get_unchecked(i) },
+        d: F::zero(),
+    });
+
+    (y.v, y.d)
+}
+
+/// Evaluate a polynomial and its integral from zero to `x` for a slice of coefficients, returning
+/// `(p(x), ∫₀ˣ p(t) dt)`. May not be monomorphized.
+///
+/// The integral is the antiderivative with a constant term of zero, `x Σ c_k x^k / (k + 1)`, evaluated
+/// with the same [`Powers`] of `x` as the polynomial itself. The value is identical to [`poly`].
+/// Each coefficient is divided by `k + 1` on every call, so when evaluating the same polynomial
+/// many times, building the antiderivative coefficients once may be faster.
+///
+/// ```rust
+/// use fast_polynomial::poly_with_integral;
+///
+/// // 1 + 2x + 3x^2, whose antiderivative is x + x^2 + x^3
+/// let (y, area) = poly_with_integral(2.0f64, &[1.0, 2.0, 3.0]);
+///
+/// assert_eq!((y, area), (17.0, 14.0));
+/// ```
+#[inline]
+pub fn poly_with_integral<F>(x: F, coeffs: &[F]) -> (F, F)
+where
+    F: PolyNum + One + Div<Output = F>,
+{
+    let powers = Powers::new(x);
+    let n = coeffs.len();
+
+    let y =
+        poly_f_powers_internal::<F, _, _, 0>(powers, n, |i| unsafe { *coeffs.get_unchecked(i) });
+    let integral = poly_f_powers_internal::<F, _, _, 0>(powers, n, |i| unsafe {
+        *coeffs.get_unchecked(i) / from_usize::<F>(i + 1)
+    });
+
+    (y, x * integral)
+}
+
+/// Evaluate a polynomial for an array of coefficients, returning the powers of `x` alongside the result
+/// for reuse. Can be monomorphized.
+///
+/// The result is identical to [`poly_array`], but all of the [`Powers`] are always computed, even those
+/// not needed for `N` coefficients, costing at most 4 multiplications. Pass them to [`poly_array_powers`]
+/// to evaluate another polynomial at the same `x`:
+///
+/// ```rust
+/// use fast_polynomial::{poly_array, poly_array_powers, poly_array_with_powers};
+///
+/// let x = 0.5f64;
+/// let (y, powers) = poly_array_with_powers(x, &[1.0, 0.3, 0.4, 1.6, 0.2, 0.1]);
+/// let z = poly_array_powers(&powers, &[0.7, 0.5, 0.9, 0.3, 0.8, 0.6]);
+///
+/// assert_eq!(y, poly_array(x, &[1.0, 0.3, 0.4, 1.6, 0.2, 0.1]));
+/// assert_eq!(z, poly_array(x, &[0.7, 0.5, 0.9, 0.3, 0.8, 0.6]));
+/// ```
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+pub fn poly_array_with_powers<F: PolyNum, const N: usize>(x: F, coeffs: &[F; N]) -> (F, Powers<F>) {
+    let powers = Powers::new(x);
+
+    (poly_array_powers(&powers, coeffs), powers)
+}
+
+/// Evaluate a polynomial for an array of coefficients, returning `(p(x), x^(N-1))`, the highest power of `x`
+/// alongside the result. Can be monomorphized.
+///
+/// The result is identical to [`poly_array`], and the highest power is multiplied together from the same
+/// [`Powers`] used to evaluate it, as with the final step of the hybrid loop, rather than with a separate `powi`.
+/// This suits normalizing the result afterwards, such as with homogeneous coordinates. As with
+/// [`poly_array_with_powers`], all of the [`Powers`] are computed. For `N <= 1`, the power is one.
+///
+/// The power is a product of `x`, `x²`, `x⁴`, `x⁸` and `x¹⁶`, according to the binary representation of `N - 1`,
+/// so may differ from `powi` by rounding.
+///
+/// ```rust
+/// use fast_polynomial::{poly_array, poly_array_with_top_power};
+///
+/// let c = [1.0f64, 0.3, 0.4, 1.6, 0.2, 0.1];
+/// let (y, top) = poly_array_with_top_power(2.0, &c);
+///
+/// assert_eq!(y, poly_array(2.0, &c));
+/// assert_eq!(top, 32.0);
+/// ```
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+pub fn poly_array_with_top_power<F: PolyNum + One, const N: usize>(
+    x: F,
+    coeffs: &[F; N],
+) -> (F, F) {
+    let powers = Powers::new(x);
+    let y = poly_array_powers(&powers, coeffs);
+
+    if N <= 1 {
+        return (y, F::one());
+    }
+
+    let (q, r) = ((N - 1) / 16, (N - 1) % 16);
+
+    let mut top = (r > 0).then(|| tail_power_dyn(r, powers.x, powers.x2, powers.x4, powers.x8));
+
+    // x^(16q) by squaring x^16
+    let mut base = powers.x16;
+    let mut e = q;
+
+    while e > 0 {
+        if e & 1 != 0 {
+            top = Some(match top {
+                Some(top) => top * base,
+                None => base,
+            });
+        }
+
+        e >>= 1;
+
+        if e > 0 {
+            base = base * base;
+        }
+    }
+
+    match top {
+        Some(top) => (y, top),
+        None => unsafe { unreachable() },
+    }
+}
+
+/// Evaluate a polynomial for an array of coefficients using precomputed [`Powers`]. Can be monomorphized.
+///
+/// The result is identical to [`poly_array`] at `powers.x`, provided the powers were computed by [`Powers::new`]
+/// or [`poly_array_with_powers`].
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+pub fn poly_array_powers<F: PolyNum, const N: usize>(powers: &Powers<F>, coeffs: &[F; N]) -> F {
+    poly_f_powers_internal::<F, _, _, N>(*powers, N, |i| unsafe { *coeffs.get_unchecked(i) })
+}
+
+/// Evaluate `K` polynomials of `N` coefficients each at the same `x`, sharing the powers of `x` between them.
+/// Can be monomorphized.
+///
+/// Each result is identical to [`poly_array`] for the corresponding polynomial, but the powers of `x` are
+/// computed only once, as with [`poly_array_powers`]. This suits multi-channel curves such as color lookup tables:
+///
+/// ```rust
+/// use fast_polynomial::{poly_array, poly_multi};
+///
+/// let rgb = [[0.0f32, 1.2, -0.2], [0.05, 0.9, 0.0], [0.1, 0.6, 0.3]];
+/// let [r, g, b] = poly_multi(0.5, &rgb);
+///
+/// assert_eq!([r, g, b], rgb.map(|c| poly_array(0.5, &c)));
+/// ```
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+pub fn poly_multi<F: PolyNum, const N: usize, const K: usize>(x: F, polys: &[[F; N]; K]) -> [F; K] {
+    let powers = Powers::new(x);
+
+    polys.map(|coeffs| poly_array_powers(&powers, &coeffs))
+}
+
+/// More flexible variant of [`poly_array`]
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+pub fn poly_array_t<F: PolyNum, T, const N: usize>(x: F, coeffs: &[T; N]) -> F
+where
+    T: Clone + Into<F>,
+{
+    poly_f_n::<F, _, N>(x, |i| unsafe { coeffs.get_unchecked(i).clone().into() })
+}
+
+/// Evaluate a polynomial for an array of coefficients with mixed precision, promoting both `x` and the
+/// coefficients to the accumulator type `A`. Can be monomorphized.
+///
+/// This is a convenience wrapper around [`poly_array_t`], which converts `x` up front and then evaluates
+/// entirely in `A`. The evaluation itself is unchanged, so [`PolyNum`] still requires a single type for
+/// both operands of each multiply-add. It allows storing the input and coefficients in a compact type while
+/// performing the evaluation with the range and precision of a wider type, such as `f32` coefficients
+/// and input with an `f64` accumulator:
+///
+/// ```rust
+/// use fast_polynomial::poly_array_mixed;
+///
+/// let c: [f32; 4] = [1.0, -3.0, 3.0, -1.0]; // (1 - x)^3
+/// let x = 0.999f32;
+///
+/// let y: f64 = poly_array_mixed(x, &c);
+///
+/// assert!((y - (1.0 - x as f64).powi(3)).abs() < 1e-14);
+/// ```
+///
+/// Each value is converted exactly once, and all powers of `x` and intermediate sums are kept in `A`, as
+/// rounding them to the narrower type would lose the benefit of the wider accumulator. Operations mixing
+/// types, such as multiplying an `f64` sum by an `f32` input, are no cheaper on typical hardware than the
+/// same operations after promotion, so there is no separate evaluation for them.
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+pub fn poly_array_mixed<A: PolyNum, X, C, const N: usize>(x: X, coeffs: &[C; N]) -> A
+where
+    X: Into<A>,
+    C: Clone + Into<A>,
+{
+    poly_array_t::<A, C, N>(x.into(), coeffs)
+}
+
+/// Polynomial with `f64` coefficients converted to `f32` once upfront, for repeated evaluation in `f32`.
+///
+/// Functions such as [`poly_array_t`] convert each coefficient on every evaluation, which is wasteful when
+/// the same coefficients are loaded once and evaluated many times. Each coefficient is rounded to the nearest
+/// `f32`, so the result will generally differ from evaluating with the original coefficients in `f64`.
+///
+/// This implements [`Coefficients`], so can also be used with [`poly_coeffs`] and [`Evaluate`].
+///
+/// Requires the `alloc` crate feature, and either the `std` or `libm` crate features.
+///
+/// ```rust
+/// use fast_polynomial::{poly, DowncastPoly};
+///
+/// let loaded: Vec<f64> = vec![1.0, 0.3, 0.4, 1.6];
+/// let p = DowncastPoly::new(&loaded);
+///
+/// assert_eq!(p.eval(0.5), poly(0.5f32, &[1.0, 0.3, 0.4, 1.6]));
+/// ```
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+#[derive(Debug, Clone, PartialEq)]
+pub struct DowncastPoly {
+    coeffs: alloc::vec::Vec<f32>,
+}
+
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+impl DowncastPoly {
+    /// Convert the coefficients to `f32`.
+    pub fn new(coeffs: &[f64]) -> Self {
+        DowncastPoly {
+            coeffs: coeffs.iter().map(|&c| c as f32).collect(),
+        }
+    }
+
+    /// The converted coefficients.
+    #[inline]
+    pub fn coeffs(&self) -> &[f32] {
+        &self.coeffs
+    }
+
+    /// Evaluate the polynomial at `x` with [`poly`].
+    #[inline]
+    pub fn eval(&self, x: f32) -> f32 {
+        poly(x, &self.coeffs)
+    }
+}
+
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+impl Coefficients<f32> for DowncastPoly {
+    #[inline(always)]
+    fn len(&self) -> usize {
+        self.coeffs.len()
+    }
+
+    #[inline(always)]
+    fn coeff(&self, i: usize) -> f32 {
+        self.coeffs[i]
+    }
+
+    #[inline(always)]
+    unsafe fn coeff_unchecked(&self, i: usize) -> f32 {
+        *self.coeffs.get_unchecked(i)
+    }
+}
+
+/// Evaluate a polynomial for a slice of coefficients that is known to be exactly `N` long. Can be monomorphized.
+///
+/// This bridges slices and [`poly_array`] for callers that can guarantee the length of the slice,
+/// but don't have it available as a typed array. The length is checked with a debug assertion,
+/// and in release builds is instead used as an optimization hint, allowing the same codegen
+/// as [`poly_array`].
+///
+/// # Safety
+///
+/// `coeffs.len()` must be equal to `N`. In debug builds or with the `paranoid` feature this is asserted,
+/// but otherwise violating this is **undefined behavior**, as coefficients may be read out of bounds.
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+pub unsafe fn poly_array_assert<F: PolyNum, const N: usize>(x: F, coeffs: &[F]) -> F {
+    debug_assert_eq!(coeffs.len(), N, "coefficient slice length does not match N");
+
+    assume(coeffs.len() == N);
+
+    poly_f_n::<F, _, N>(x, |i| unsafe { *coeffs.get_unchecked(i) })
+}
+
+/// Evaluate a polynomial for an array of coefficients without fused multiply-add. Can be monomorphized.
+///
+/// Every step is a separately-rounded multiply and add, even when the `fma` target feature is enabled,
+/// while otherwise following the same order of operations as [`poly_array`]. This is intended for
+/// coefficients that were fitted assuming strict IEEE 754 multiplication and addition, where fusing
+/// would change the approximation error away from its designed bounds. To disable fused multiply-add
+/// for all evaluation routines instead, see the `deterministic` crate feature.
+///
+/// Only fusion performed by this crate is affected; a type whose own [`Mul`] or [`Add`] fuses internally
+/// will still do so.
+///
+/// ```rust
+/// use fast_polynomial::poly_array_no_contract;
+///
+/// let c = [1.0, 0.3, 0.4, 1.6];
+/// let x = 0.1f64;
+///
+/// assert_eq!(poly_array_no_contract(x, &c), (c[0] + c[1] * x) + (c[2] + c[3] * x) * (x * x));
+/// ```
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+pub fn poly_array_no_contract<F: PolyNum, const N: usize>(x: F, coeffs: &[F; N]) -> F {
+    poly_array_unfused(x, coeffs)
+}
+
+/// [`poly_array_no_contract`] for types without [`MulAdd`], such as `f64` without the `std` or `libm` crate features.
+#[inline(always)]
+pub(crate) fn poly_array_unfused<F: Unfused, const N: usize>(x: F, coeffs: &[F; N]) -> F {
+    poly_f_n::<_, _, N>(NoContract(x), |i| unsafe {
+        NoContract(*coeffs.get_unchecked(i))
+    })
+    .0
+}
+
+/// Evaluate a polynomial for a slice of coefficients that is expected to be exactly `N` long. Can be monomorphized.
+///
+/// This is the safe counterpart to [`poly_array_assert`], returning [`PolyError::LengthMismatch`] if the length
+/// of the slice is not `N`, rather than assuming it.
+///
+/// ```rust
+/// use fast_polynomial::{poly_array, try_poly_array, PolyError};
+///
+/// let c = vec![1.0, 0.3, 0.4, 1.6];
+///
+/// assert_eq!(try_poly_array::<f64, 4>(0.5, &c), Ok(poly_array(0.5, &[1.0, 0.3, 0.4, 1.6])));
+/// assert_eq!(try_poly_array::<f64, 8>(0.5, &c), Err(PolyError::LengthMismatch { expected: 8, found: 4 }));
+/// ```
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+pub fn try_poly_array<F: PolyNum, const N: usize>(x: F, coeffs: &[F]) -> Result<F, PolyError> {
+    match <&[F; N]>::try_from(coeffs) {
+        Ok(coeffs) => Ok(poly_array(x, coeffs)),
+        Err(_) => Err(PolyError::LengthMismatch {
+            expected: N,
+            found: coeffs.len(),
+        }),
+    }
+}
+
+/// Compute the bottom level of Estrin's scheme for an array of coefficients, such that
+/// `leaves[i] = coeffs[2i] + x * coeffs[2i + 1]`. Can be monomorphized.
+///
+/// If `N` is odd, the last leaf is the last coefficient alone. The polynomial can then be finished
+/// by evaluating the leaves as a polynomial in `x²`, or combined with a custom reduction tree:
+///
+/// ```rust
+/// use fast_polynomial::{poly, poly_array_leaves};
+///
+/// let c = [1.0, 0.3, 0.4, 1.6, -0.5];
+/// let x = 0.5f64;
+///
+/// let mut leaves = [0.0; 3];
+/// poly_array_leaves(x, &c, &mut leaves);
+///
+/// assert!((poly(x * x, &leaves) - poly(x, &c)).abs() < 1e-12);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `leaves.len()` is not `(N + 1) / 2`.
+#[inline]
+pub fn poly_array_leaves<F: PolyNum, const N: usize>(x: F, coeffs: &[F; N], leaves: &mut [F]) {
+    assert_eq!(
+        leaves.len(),
+        N.div_ceil(2),
+        "leaves must have length (N + 1) / 2"
+    );
+
+    let pairs = coeffs.chunks_exact(2);
+
+    if let [c] = *pairs.remainder() {
+        leaves[N / 2] = c;
+    }
+
+    for (leaf, pair) in leaves.iter_mut().zip(pairs) {
+        *leaf = fma(x, pair[1], pair[0]);
+    }
+}
+
+/// More flexible variant of [`rational_array`]
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+pub fn rational_array_t<F: PolyRational, T, const P: usize, const Q: usize>(
+    x: F,
+    numerator: &[T; P],
+    denomiator: &[T; Q],
+) -> F
+where
+    T: Clone + Into<F>,
+{
+    rational_f_n::<F, _, _, P, Q>(
+        x,
+        |i| unsafe { numerator.get_unchecked(i).clone().into() },
+        |i| unsafe { denomiator.get_unchecked(i).clone().into() },
+    )
+}
+
+/// Evaluate a polynomial for a slice of coefficients. May not be monomorphized.
+///
+/// To not be monomorphized means this function's codegen may be used for any number of coefficients,
+/// and therefore contains branches. It will be faster to use [`poly_array`] instead if possible.
+///
+/// # Overflow
+///
+/// For 32 or more coefficients, powers of `x` up to `x^16` are computed upfront, which may
+/// overflow to infinity for large `|x|` even if the coefficients decay such that the result would
+/// be finite, producing an infinite or `NaN` result. Use [`accurate::poly_stable`] for such inputs.
+pub fn poly<F: PolyNum>(x: F, coeffs: &[F]) -> F {
+    poly_f_internal::<F, _, 0>(x, coeffs.len(), |i| unsafe { *coeffs.get_unchecked(i) })
+}
+
+/// Evaluate a polynomial for a slice of coefficients, with a hint that the slice is exactly `expected_len` long.
+/// May not be monomorphized.
+///
+/// [`poly`] dispatches on the length of the slice at runtime, even in hot loops where the length is invariant,
+/// as the optimizer usually can't prove it. When `expected_len` is a constant, or otherwise known to the optimizer,
+/// this hint allows that dispatch to be folded away, giving the same codegen as [`poly_array`]. If `expected_len`
+/// is only known at runtime, the hint has no effect.
+///
+/// # Safety
+///
+/// `coeffs.len()` must be equal to `expected_len`. In debug builds or with the `paranoid` feature this is asserted,
+/// but otherwise violating this is **undefined behavior**, as coefficients may be read out of bounds.
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+pub unsafe fn poly_hint<F: PolyNum>(x: F, coeffs: &[F], expected_len: usize) -> F {
+    assume(coeffs.len() == expected_len);
+
+    poly_f_internal::<F, _, 0>(x, expected_len, |i| unsafe { *coeffs.get_unchecked(i) })
+}
+
+/// Evaluate a polynomial for a slice of coefficients, dispatching to a monomorphized [`poly_array`] for common lengths.
+///
+/// This is intended for coefficients whose length is only known at runtime, such as a `Vec` loaded
+/// from a configuration file, but is typically one of a few small sizes. The specialized lengths are
+/// 0 through 16 and 32, matching the hand-unrolled [`polynomials`] up to [`poly_15`](polynomials::poly_15)
+/// and [`poly_31`](polynomials::poly_31). All other lengths fall back to [`poly`].
+///
+/// The dispatch itself is a single jump on the length, but every specialized length is a separate
+/// inlined instance of [`poly_array`], so this generates considerably more code than [`poly`]. Prefer
+/// [`poly_array`] directly when the length is known at compile time.
+///
+/// ```rust
+/// use fast_polynomial::{poly, poly_vec};
+///
+/// let c = vec![1.0, 0.3, 0.4, 1.6, 0.2, 0.1, 0.7, 0.5];
+///
+/// assert_eq!(poly_vec(0.5f64, &c), poly(0.5, &c));
+/// ```
+#[inline]
+pub fn poly_vec<F: PolyNum>(x: F, coeffs: &[F]) -> F {
+    macro_rules! dispatch {
+        ($($n:literal)*) => {
+            match coeffs.len() {
+                // SAFETY: the length of the slice was just checked
+                $($n => poly_array::<F, $n>(x, unsafe { &*(coeffs.as_ptr() as *const [F; $n]) }),)*
+                _ => poly(x, coeffs),
+            }
+        };
+    }
+
+    dispatch!(0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 32)
+}
+
+/// Evaluate a polynomial for a slice of coefficients, choosing between Estrin's scheme and Horner's method
+/// based on the number of coefficients and the size of `F`.
+///
+/// Estrin's scheme, as used by [`poly`], shortens the dependency chain at the cost of keeping more
+/// intermediate values live at once. For most types this is a clear win, but for very wide types
+/// those intermediate values no longer fit in registers, and the spills outweigh the gained parallelism.
+/// Horner's method, as in [`accurate::poly_stable`], keeps only a single value live.
+///
+/// The decision is made as follows, derived from the `auto` group of the `schemes` benchmark on `x86_64`:
+///
+/// | `size_of::<F>()` | Coefficients | Scheme  | Example types                       |
+/// |------------------|--------------|---------|-------------------------------------|
+/// | < 128 bytes      | any          | Estrin  | `f32`, `f64`, `ArrayWrap<8, f64>`, [`accurate::DoubleDouble`] |
+/// | >= 128 bytes     | < 16         | Estrin  | `ArrayWrap<16, f64>`                |
+/// | >= 128 bytes     | >= 16        | Horner  | `ArrayWrap<16, f64>`, `ArrayWrap<32, f32>` |
+///
+/// For scalar floats, Estrin's scheme measured 2-3x faster for both short polynomials and
+/// those of 32 or more coefficients, while for `ArrayWrap<16, f64>` with 16 or more coefficients,
+/// Horner's method was as fast or up to 20% faster. These thresholds are necessarily approximate, and the best
+/// choice for a particular target and type should be confirmed by benchmarking.
+#[inline]
+pub fn poly_auto<F: PolyNum>(x: F, coeffs: &[F]) -> F {
+    if core::mem::size_of::<F>() >= 128 && coeffs.len() >= 16 {
+        accurate::poly_stable(x, coeffs)
+    } else {
+        poly(x, coeffs)
+    }
+}
+
+/// Evaluate a polynomial with `count` coefficients taken from every `stride`-th element of `coeffs`,
+/// such that the coefficient of `x^i` is `coeffs[i * stride]`. May not be monomorphized.
+///
+/// This allows evaluating polynomials stored non-contiguously without copying, such as
+/// a column of a row-major matrix, where `stride` is the number of columns:
+///
+/// ```rust
+/// use fast_polynomial::{poly, poly_strided};
+///
+/// // 3 polynomials stored as columns of a 4x3 row-major matrix
+/// let m = [
+///     1.0, 2.0, 3.0,
+///     0.3, 0.2, 0.1,
+///     0.4, 0.5, 0.6,
+///     1.6, 1.5, 1.4,
+/// ];
+///
+/// let y = poly_strided(0.5, &m[1..], 3, 4);
+///
+/// assert_eq!(y, poly(0.5, &[2.0, 0.2, 0.5, 1.5]));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `count > 0` and `(count - 1) * stride >= coeffs.len()`,
+/// as the last coefficient would be out of bounds.
+pub fn poly_strided<F: PolyNum>(x: F, coeffs: &[F], stride: usize, count: usize) -> F {
+    if count > 0 {
+        let last = (count - 1).checked_mul(stride);
+
+        assert!(
+            matches!(last, Some(last) if last < coeffs.len()),
+            "strided coefficients out of bounds"
+        );
+    }
+
+    poly_f_internal::<F, _, 0>(x, count, |i| unsafe { *coeffs.get_unchecked(i * stride) })
+}
+
+/// Evaluate a polynomial with `perm.len()` coefficients taken from `coeffs` through an index mapping,
+/// such that the coefficient of `x^i` is `coeffs[perm[i]]`. May not be monomorphized.
+///
+/// This allows evaluating coefficients stored in a permuted order without reordering them first,
+/// such as the bit-reversed output of an in-place FFT:
+///
+/// ```rust
+/// use fast_polynomial::{poly, poly_permuted};
+///
+/// // coefficients 0..8 stored in bit-reversed order
+/// let stored = [1.0, 0.5, 0.3, 0.7, 0.2, 0.6, 0.4, 0.8];
+/// let perm = [0, 4, 2, 6, 1, 5, 3, 7];
+///
+/// let y = poly_permuted(0.5f64, &stored, &perm);
+///
+/// assert_eq!(y, poly(0.5, &[1.0, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8]));
+/// ```
+///
+/// Indices may be repeated or omitted. Each index is bounds-checked as it is used, which along with
+/// the indirection itself is slower than [`poly`] on contiguous coefficients, but for a single evaluation
+/// is still cheaper than reordering the coefficients beforehand.
+///
+/// # Panics
+///
+/// Panics if any index in `perm` is out of bounds for `coeffs`.
+pub fn poly_permuted<F: PolyNum>(x: F, coeffs: &[F], perm: &[usize]) -> F {
+    poly_f_internal::<F, _, 0>(x, perm.len(), |i| coeffs[unsafe { *perm.get_unchecked(i) }])
+}
+
+/// Evaluate a polynomial with palindromic coefficients, `c_k = c_(n-1-k)`, given only the first half. May not be monomorphized.
+///
+/// The full polynomial has `n = 2 * half_coeffs.len()` coefficients, or one fewer if `odd_length` is true,
+/// in which case the last element of `half_coeffs` is the unpaired middle coefficient. For example, `[1, 2, 3, 2, 1]`
+/// is given as `half_coeffs = [1, 2, 3]` with `odd_length = true`, and `[1, 2, 2, 1]` as `[1, 2]` with `odd_length = false`.
+///
+/// The mirrored half of the polynomial, `Σ c_k x^(n-1-k)`, is `x^h` times the first half with its coefficients reversed,
+/// where `h = half_coeffs.len()`, so both halves are evaluated from the same coefficients and combined as
+/// `A(x) + x^h R(x)`. This reads only half the coefficients, such as for symmetric FIR filters, and the two
+/// halves are independent so can be evaluated in parallel. Unlike folding the samples of a symmetric FIR filter,
+/// this doesn't reduce the number of multiplications, as `x^k` and `x^(n-1-k)` differ.
+///
+/// ```rust
+/// use fast_polynomial::{poly, poly_symmetric};
+///
+/// let x = 0.5f64;
+///
+/// assert_eq!(poly_symmetric(x, &[1.0, 2.0, 3.0], true), poly(x, &[1.0, 2.0, 3.0, 2.0, 1.0]));
+/// assert_eq!(poly_symmetric(x, &[1.0, 2.0], false), poly(x, &[1.0, 2.0, 2.0, 1.0]));
+/// ```
+///
+/// For anti-palindromic coefficients, `c_k = -c_(n-1-k)`, evaluate the first half and mirrored half separately
+/// with [`poly`] and [`poly_f`], and subtract them instead.
+pub fn poly_symmetric<F: PolyNum>(x: F, half_coeffs: &[F], odd_length: bool) -> F {
+    let h = half_coeffs.len();
+
+    if h == 0 {
+        return F::zero();
+    }
+
+    // the middle coefficient of an odd-length polynomial is not mirrored
+    let m = h - odd_length as usize;
+
+    let a = poly_f_internal::<F, _, 0>(x, h, |i| unsafe { *half_coeffs.get_unchecked(i) });
+
+    if m == 0 {
+        return a;
+    }
+
+    let r = poly_f_internal::<F, _, 0>(x, m, |i| unsafe { *half_coeffs.get_unchecked(m - 1 - i) });
+
+    fma(powi_nonzero(x, h), r, a)
+}
+
+/// Evaluate a polynomial given its even- and odd-indexed coefficients separately,
+/// as `p_even(x²) + x p_odd(x²)`. May not be monomorphized.
+///
+/// `even[k]` is the coefficient of `x^(2k)` and `odd[k]` the coefficient of `x^(2k+1)`, so the full
+/// polynomial is the two interleaved, and the slices may have any lengths. Each half is evaluated with
+/// [`poly`] at `x²`, and the two are independent so can be evaluated in parallel. This avoids interleaving
+/// coefficients that are already stored split, such as the polyphase components of an FIR filter.
+///
+/// ```rust
+/// use fast_polynomial::{poly, poly_even_odd};
+///
+/// let x = 0.5f64;
+///
+/// assert_eq!(poly_even_odd(x, &[1.0, 3.0, 5.0], &[2.0, 4.0]), poly(x, &[1.0, 2.0, 3.0, 4.0, 5.0]));
+/// ```
+///
+/// If `odd` is empty, the result is only a polynomial in `x²`, which for a single even coefficient
+/// returns it without depending on `x`, as with [`poly`].
+pub fn poly_even_odd<F: PolyNum>(x: F, even: &[F], odd: &[F]) -> F {
+    let x2 = x * x;
+
+    let e = poly(x2, even);
+
+    if odd.is_empty() {
+        return e;
+    }
+
+    fma(x, poly(x2, odd), e)
+}
+
+/// Evaluate a polynomial for a slice of coefficients, prefetching upcoming coefficients into cache.
+///
+/// Identical in result to [`poly`], but intended for very large polynomials whose coefficients
+/// do not fit in the L1 cache. The hybrid loop processes coefficients in blocks of 16 from the
+/// highest degree down, which is the opposite of the direction hardware prefetchers are typically
+/// best at, so this explicitly requests each block well ahead of when it is required.
+///
+/// Prefetch hints are only issued on `x86` and `x86_64`, and elsewhere this is equivalent to [`poly`].
+/// For small polynomials the extra hints will only slow down evaluation.
+pub fn poly_prefetch<F: PolyNum>(x: F, coeffs: &[F]) -> F {
+    poly_f_internal::<F, _, 0>(x, coeffs.len(), |i| {
+        // once per block of 16 coefficients
+        if i % 16 == 0 {
+            prefetch_block::<F>(coeffs.as_ptr().wrapping_add(i));
+        }
+
+        unsafe { *coeffs.get_unchecked(i) }
+    })
+}
+
+/// Evaluate a polynomial for a slice of coefficients, storing the sums of each block of 16 coefficients
+/// in `scratch` before combining them. May not be monomorphized.
+///
+/// [`poly_array`] for a large `N` may be unrolled into a single expression, and with a wide `F` such as
+/// [`ArrayWrap<16, f64>`](many_xs::ArrayWrap), the temporaries of that expression can use a lot of stack,
+/// which matters on embedded targets with small stacks. Here, each block is evaluated into `scratch` in turn,
+/// so only the temporaries of a single block are ever live on the stack, and the blocks are then combined in the
+/// same order as the hybrid loop of [`poly`]. Without the `multi-accumulator` feature, the result is identical to [`poly`].
+///
+/// `scratch` must hold at least `coeffs.len() / 16` values, and can be reused between calls.
+/// Polynomials with up to 16 coefficients are evaluated directly with [`poly`], without using `scratch`.
+///
+/// ```rust
+/// use fast_polynomial::{poly, poly_slice_scratch};
+///
+/// let c: Vec<f64> = (0..256).map(|i| 1.0 / (i + 1) as f64).collect();
+/// let mut scratch = [0.0; 16];
+///
+/// assert_eq!(poly_slice_scratch(0.5, &c, &mut scratch), poly(0.5, &c));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `scratch` has fewer than `coeffs.len() / 16` values when there are more than 16 coefficients.
+pub fn poly_slice_scratch<F: PolyNum>(x: F, coeffs: &[F], scratch: &mut [F]) -> F {
+    let n = coeffs.len();
+
+    if n <= 16 {
+        return poly(x, coeffs);
+    }
+
+    // the highest blocks are full, leaving any remainder in the lowest
+    let r = n % 16;
+    let blocks = coeffs[r..].chunks_exact(16);
+    let count = blocks.len();
+
+    assert!(
+        scratch.len() >= count,
+        "scratch must hold at least {count} values, found {}",
+        scratch.len()
+    );
+
+    let p = Powers::new(x);
+
+    for (s, block) in scratch.iter_mut().zip(blocks) {
+        *s = poly_array_powers::<F, 16>(&p, block.try_into().unwrap());
+    }
+
+    let mut sum = scratch[count - 1];
+
+    for &s in scratch[..count - 1].iter().rev() {
+        sum = fma(p.x16, sum, s);
+    }
+
+    if r == 0 {
+        return sum;
+    }
+
+    let res = poly_f_powers_internal::<F, _, _, 0>(p, r, |i| unsafe { *coeffs.get_unchecked(i) });
+
+    fma(tail_power_dyn(r, p.x, p.x2, p.x4, p.x8), sum, res)
+}
+
+/// Prefetch the block of 16 coefficients a fixed distance below `ptr`.
+#[inline(always)]
+#[allow(unused_variables)]
+fn prefetch_block<F>(ptr: *const F) {
+    // distance in bytes, roughly enough to cover memory latency at the rate blocks are consumed
+    const DISTANCE: usize = 1024;
+    const LINE: usize = 64;
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    {
+        #[cfg(target_arch = "x86")]
+        use core::arch::x86::{_mm_prefetch, _MM_HINT_T0};
+        #[cfg(target_arch = "x86_64")]
+        use core::arch::x86_64::{_mm_prefetch, _MM_HINT_T0};
+
+        let base = (ptr as *const i8).wrapping_sub(DISTANCE);
+
+        let mut offset = 0;
+        while offset < 16 * core::mem::size_of::<F>() {
+            // prefetching never dereferences the pointer, so is safe for any address
+            unsafe { _mm_prefetch::<_MM_HINT_T0>(base.wrapping_add(offset)) };
+            offset += LINE;
+        }
+    }
+}
+
+/// Evaluate a rational polynomial for slices of coefficients, returning [`PolyError::EmptyDenominator`]
+/// if the denominator has no coefficients. May not be monomorphized.
+///
+/// Otherwise, this is identical to [`rational`], which would instead divide by zero.
+///
+/// ```rust
+/// use fast_polynomial::{rational, try_rational, PolyError};
+///
+/// assert_eq!(try_rational(0.5, &[1.0, 2.0], &[3.0, 4.0]), Ok(rational(0.5, &[1.0, 2.0], &[3.0, 4.0])));
+/// assert_eq!(try_rational(0.5, &[1.0, 2.0], &[]), Err(PolyError::EmptyDenominator));
+/// ```
+pub fn try_rational<F: PolyRational>(
+    x: F,
+    numerator: &[F],
+    denominator: &[F],
+) -> Result<F, PolyError> {
+    if denominator.is_empty() {
+        return Err(PolyError::EmptyDenominator);
+    }
+
+    Ok(rational(x, numerator, denominator))
+}
+
+/// Evaluate a polynomial for a slice of coefficients, returning [`PolyError::NonFinite`] if the result
+/// is infinite or NaN. May not be monomorphized.
+///
+/// Otherwise, this is identical to [`poly`]. Note that for 32 or more coefficients, powers of `x` may
+/// overflow even if the result would not, as described for [`poly`].
+///
+/// Requires either the `std` or `libm` crate features.
+///
+/// ```rust
+/// use fast_polynomial::{poly_finite, PolyError};
+///
+/// assert_eq!(poly_finite(2.0f64, &[1.0, 1.0]), Ok(3.0));
+/// assert_eq!(poly_finite(1e300f64, &[1.0, 0.0, 1.0]), Err(PolyError::NonFinite));
+/// assert_eq!(poly_finite(f64::NAN, &[1.0, 1.0]), Err(PolyError::NonFinite));
+/// ```
+#[cfg(any(feature = "std", feature = "libm"))]
+pub fn poly_finite<F: PolyNum + num_traits::Float>(x: F, coeffs: &[F]) -> Result<F, PolyError> {
+    let y = poly(x, coeffs);
+
+    if y.is_finite() {
+        Ok(y)
+    } else {
+        Err(PolyError::NonFinite)
+    }
+}
+
+/// Evaluate a rational polynomial for an array of coefficients. May not be monomorphized.
+///
+/// To not be monomorphized means this function's codegen may be used for any number of coefficients,
+/// and therefore contains branches. It will be faster to use [`rational_array`] instead if possible.
+pub fn rational<F: PolyRational>(x: F, numerator: &[F], denominator: &[F]) -> F {
+    rational_f_internal::<F, _, _, 0, 0>(
+        x,
+        numerator.len(),
+        denominator.len(),
+        |i| unsafe { *numerator.get_unchecked(i) },
+        |i| unsafe { *denominator.get_unchecked(i) },
+    )
+}
+
+/// Evaluate a polynomial for any container of [`Coefficients`].
+///
+/// If the length of the container is known at compile-time, such as for arrays, this is equivalent
+/// to [`poly_array`]. Otherwise, it is equivalent to [`poly`].
+///
+/// ```rust
+/// use fast_polynomial::poly_coeffs;
+///
+/// let c = [1.0, 0.3, 0.4, 1.6];
+///
+/// assert_eq!(poly_coeffs(0.5, &c[..]), poly_coeffs(0.5, c));
+/// ```
+#[inline]
+pub fn poly_coeffs<F: PolyNum, C: Coefficients<F>>(x: F, coeffs: C) -> F {
+    let n = coeffs.len();
+
+    // `LEN` is not trusted, so is only used when it matches the actual length
+    if C::LEN > 0 && n == C::LEN {
+        return poly_f_internal::<F, _, 0>(x, C::LEN, |i| unsafe { coeffs.coeff_unchecked(i) });
+    }
+
+    poly_f_internal::<F, _, 0>(x, n, |i| unsafe { coeffs.coeff_unchecked(i) })
+}
+
+/// Evaluate a polynomial for coefficients provided through a [`CoeffSource`] trait object. Not monomorphized.
+///
+/// Each coefficient is requested exactly once, through a dynamic call, so this is slower than
+/// [`poly`] for coefficients already in memory, but a single instance serves every provider.
+///
+/// ```rust
+/// use fast_polynomial::{poly, poly_dyn, CoeffSource};
+///
+/// /// Coefficients of `Σ x^k / k!`, computed on demand
+/// struct Exp(usize);
+///
+/// impl CoeffSource for Exp {
+///     fn len(&self) -> usize { self.0 }
+///     fn coeff(&self, i: usize) -> f64 { 1.0 / (1..=i).product::<usize>() as f64 }
+/// }
+///
+/// let sources: [&dyn CoeffSource; 2] = [&Exp(20), &[1.0, 0.3, 0.4, 1.6]];
+///
+/// assert!((poly_dyn(1.0, sources[0]) - 1f64.exp()).abs() < 1e-15);
+/// assert_eq!(poly_dyn(0.5, sources[1]), poly(0.5, &[1.0, 0.3, 0.4, 1.6]));
+/// ```
+#[inline]
+pub fn poly_dyn<F: PolyNum>(x: F, src: &dyn CoeffSource<F>) -> F {
+    poly_f_internal::<F, _, 0>(x, src.len(), |i| src.coeff(i))
+}
+
+/// Evaluate a rational polynomial for any containers of [`Coefficients`].
+///
+/// See [`poly_coeffs`] and [`rational`] for details.
+#[inline]
+pub fn rational_coeffs<F: PolyRational, N, D>(x: F, numerator: N, denominator: D) -> F
+where
+    N: Coefficients<F>,
+    D: Coefficients<F>,
+{
+    let (p, q) = (numerator.len(), denominator.len());
+
+    // see `poly_coeffs`
+    if (N::LEN == 0 || p == N::LEN) && (D::LEN == 0 || q == D::LEN) {
+        return rational_f_internal::<F, _, _, 0, 0>(
+            x,
+            if N::LEN > 0 { N::LEN } else { p },
+            if D::LEN > 0 { D::LEN } else { q },
+            |i| unsafe { numerator.coeff_unchecked(i) },
+            |i| unsafe { denominator.coeff_unchecked(i) },
+        );
+    }
+
+    rational_f_internal::<F, _, _, 0, 0>(
+        x,
+        p,
+        q,
+        |i| unsafe { numerator.coeff_unchecked(i) },
+        |i| unsafe { denominator.coeff_unchecked(i) },
+    )
+}
+
+/// Evaluate a polynomial composed with an affine map, `p(a * x + b)`, for a slice of coefficients.
+///
+/// This computes `u = a * x + b` once and evaluates the polynomial at `u` as normal. If evaluating
+/// many points with the same affine map, it may be faster to precompute the composed coefficients
+/// with [`algebra::compose_affine`] and use [`poly`] directly.
+#[inline]
+pub fn poly_affine<F: PolyNum>(x: F, a: F, b: F, coeffs: &[F]) -> F {
+    poly(fma(x, a, b), coeffs)
+}
+
+/// Evaluate a polynomial with coefficients given inline, such as literals.
+///
+/// Expands to [`poly_array`] for the given coefficients, which is always monomorphized and inlined
+/// into the optimal Estrin's scheme expression of the [`polynomials`] module for that number of coefficients.
+/// With literal coefficients, the result is a single expression of fused multiply-adds with constant operands,
+/// as would be written by hand for the implementation of special functions.
+///
+/// ```rust
+/// use fast_polynomial::poly;
+///
+/// let x = 0.5f64;
+/// let y = poly!(x; 1.0, -0.5, 0.04166, -0.00138);
+///
+/// assert_eq!(y, poly(x, &[1.0, -0.5, 0.04166, -0.00138]));
+/// ```
+#[macro_export]
+macro_rules! poly {
+    ($x:expr; $($c:expr),* $(,)?) => {
+        $crate::poly_array($x, &[$($c),*])
+    };
+}
+
+/// Evaluate a sparse polynomial with coefficients at exponents known at compile-time.
+///
+/// Expands to a sequence of fused multiply-adds of each coefficient with the required power of `x`,
+/// where each power is computed by repeated squaring. Because the exponents are constant, the power
+/// computations are fully unrolled and common powers shared between terms by the optimizer,
+/// so only the powers actually required are computed.
+///
+/// Exponents must be integer literals or constants, and coefficients may be any expression.
+/// The type must implement [`One`] in addition to [`PolyNum`].
+///
+/// ```rust
+/// use fast_polynomial::sparse_poly;
+///
+/// let x = 0.5f64;
+/// let y = sparse_poly!(x; 0 => 1.0, 3 => -0.5, 7 => 0.25);
+///
+/// assert!((y - (1.0 - 0.5 * x.powi(3) + 0.25 * x.powi(7))).abs() < 1e-12);
+/// ```
+#[macro_export]
+macro_rules! sparse_poly {
+    ($x:expr; $($k:expr => $c:expr),+ $(,)?) => {{
+        let x = $x;
+        let mut sum = $crate::__zero_like(x);
+        $(sum = $crate::__sparse_term(x, $k, $c, sum);)+
+        sum
+    }};
+}
+
+#[doc(hidden)]
+#[inline(always)]
+pub fn __zero_like<F: PolyNum>(_: F) -> F {
+    F::zero()
+}
+
+#[doc(hidden)]
+#[inline(always)]
+pub fn __sparse_term<F: PolyNum + One>(x: F, k: u32, c: F, sum: F) -> F {
+    fma(powi(x, k), c, sum)
+}
+
+/// Raise `base` to an integer power by binary exponentiation.
+///
+/// Unlike `f64::powi`, this is available for any [`PolyNum`], including `no_std` floats
+/// and custom number types, and only uses multiplication, so gives identical results
+/// regardless of whether fused multiply-add is available. This requires `O(log2(exp))` multiplications.
+///
+/// [`One`] is required to return `powi(base, 0) == 1`.
+///
+/// ```rust
+/// use fast_polynomial::powi;
+///
+/// assert_eq!(powi(3u64, 13), 1594323);
+/// assert_eq!(powi(1.5f64, 0), 1.0);
+/// ```
+#[inline]
+pub fn powi<F: PolyNum + One>(base: F, exp: u32) -> F {
+    if exp == 0 {
+        return F::one();
+    }
+
+    powi_nonzero(base, exp as usize)
+}
+
+/// Compute a single term of a polynomial, `c * x^k`, with [`powi`].
+///
+/// If `k == 0`, this returns `c` without depending on `x`, such that `term(c, NaN, 0) == c`. Otherwise, `x^k`
+/// is computed first, so may overflow to infinity or underflow to zero even if the product with `c`
+/// would be representable. Sums of such terms with constant exponents are better expressed with [`sparse_poly!`],
+/// which shares common powers between terms.
+///
+/// ```rust
+/// use fast_polynomial::term;
+///
+/// assert_eq!(term(3.0f64, 2.0, 10), 3072.0);
+/// assert_eq!(term(3.0f64, f64::NAN, 0), 3.0);
+/// ```
+#[inline]
+pub fn term<F: PolyNum>(c: F, x: F, k: u32) -> F {
+    if k == 0 {
+        return c;
+    }
+
+    c * powi_nonzero(x, k as usize)
+}
+
+/// Evaluate a sparse polynomial given as a map from exponents to coefficients.
+///
+/// As a `BTreeMap` iterates in order of increasing exponent, the power of `x` for each term is
+/// updated from that of the previous term by raising `x` to the difference in exponents with [`powi`],
+/// rather than computing each power from scratch. Zero coefficients are not skipped, so will still
+/// propagate infinite or NaN powers of `x`.
+///
+/// For exponents known at compile-time, prefer [`sparse_poly!`].
+///
+/// ```rust
+/// use std::collections::BTreeMap;
+/// use fast_polynomial::poly_sparse_map;
+///
+/// let terms = BTreeMap::from([(0, 1.0), (3, -0.5), (7, 0.25)]);
+/// let x = 1.5f64;
+///
+/// assert!((poly_sparse_map(x, &terms) - (1.0 - 0.5 * x.powi(3) + 0.25 * x.powi(7))).abs() < 1e-12);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn poly_sparse_map<F: PolyNum + One>(x: F, terms: &alloc::collections::BTreeMap<u32, F>) -> F {
+    let mut sum = F::zero();
+    let mut power = F::one();
+    let mut last = 0;
+
+    for (&k, &c) in terms {
+        if k != last {
+            power = power * powi_nonzero(x, (k - last) as usize);
+            last = k;
+        }
+
+        sum = fma(power, c, sum);
+    }
+
+    sum
+}
+
+/// Fill `out` with consecutive powers of `x`, such that `out[k] = x^k`.
+///
+/// Each power is computed from the previous with a single multiplication, which is useful
+/// for building Vandermonde matrices for interpolation, or supplying the powers of `x`
+/// required by the [`polynomials`] module. For large `k`, the rounding error grows linearly
+/// rather than logarithmically as with [`powi`].
+///
+/// ```rust
+/// use fast_polynomial::fill_powers;
+///
+/// let mut powers = [0.0; 5];
+/// fill_powers(2.0, &mut powers);
+///
+/// assert_eq!(powers, [1.0, 2.0, 4.0, 8.0, 16.0]);
+/// ```
+#[inline]
+pub fn fill_powers<F: PolyNum + One>(x: F, out: &mut [F]) {
+    if let Some(first) = out.first_mut() {
+        *first = F::one();
+    }
+
+    for k in 1..out.len() {
+        out[k] = out[k - 1] * x;
+    }
+}
+
+/// `u^e` by binary exponentiation, assuming `e > 0`.
+#[inline(always)]
+fn powi_nonzero<F: PolyNum>(mut u: F, mut e: usize) -> F {
+    // skip squarings that never contribute to the result, to avoid starting from one
+    while e & 1 == 0 {
+        u = u * u;
+        e >>= 1;
+    }
+
+    let mut res = u;
+
+    loop {
+        e >>= 1;
+
+        if e == 0 {
+            return res;
+        }
+
+        u = u * u;
+
+        if e & 1 != 0 {
+            res = res * u;
+        }
+    }
+}
+
+/// Evaluate a polynomial with quantized `i8` coefficients at an `i16` input, accumulating in `i32`.
+///
+/// Each coefficient and the input are widened to `i32` before use, and the polynomial is evaluated
+/// with Horner's method, as the upfront powers of `x` in Estrin's scheme would overflow far sooner.
+/// This is intended for fixed-point filtering on targets where floating-point is unavailable.
+///
+/// Intermediate results wrap on overflow rather than panicking. Because wrapping arithmetic is exact
+/// modulo 2³², the result is still exact whenever the true value of the polynomial fits in an `i32`,
+/// even if intermediate values did not.
+///
+/// ```rust
+/// use fast_polynomial::poly_quantized;
+///
+/// assert_eq!(poly_quantized(-300, &[127, -128, 5]), 127 + 38400 + 450000);
+/// ```
+#[inline]
+pub fn poly_quantized(x: i16, coeffs: &[i8]) -> i32 {
+    let x = x as i32;
+
+    coeffs
+        .iter()
+        .rev()
+        .fold(0i32, |sum, &c| sum.wrapping_mul(x).wrapping_add(c as i32))
+}
+
+/// Evaluate a polynomial with quantized `i8` coefficients at an `i16` input, returning [`PolyError::Overflow`]
+/// if any intermediate result overflows an `i32`.
+///
+/// Otherwise, this is identical to [`poly_quantized`], which instead wraps on overflow.
+///
+/// ```rust
+/// use fast_polynomial::{poly_quantized_checked, PolyError};
+///
+/// assert_eq!(poly_quantized_checked(-300, &[127, -128, 5]), Ok(127 + 38400 + 450000));
+/// assert_eq!(poly_quantized_checked(i16::MAX, &[0, 0, 0, 1]), Err(PolyError::Overflow));
+/// ```
+#[inline]
+pub fn poly_quantized_checked(x: i16, coeffs: &[i8]) -> Result<i32, PolyError> {
+    let x = x as i32;
+
+    coeffs.iter().rev().try_fold(0i32, |sum, &c| {
+        sum.checked_mul(x)
+            .and_then(|sum| sum.checked_add(c as i32))
+            .ok_or(PolyError::Overflow)
+    })
 }
 
-/// More flexible variant of [`rational_array`]
-#[inline(always)]
-pub fn rational_array_t<F: PolyRational, T, const P: usize, const Q: usize>(
-    x: F,
-    numerator: &[T; P],
-    denomiator: &[T; Q],
-) -> F
-where
-    T: Clone + Into<F>,
-{
-    rational_f_n::<F, _, _, P, Q>(
-        x,
-        |i| unsafe { numerator.get_unchecked(i).clone().into() },
-        |i| unsafe { denomiator.get_unchecked(i).clone().into() },
-    )
+/// Evaluate a polynomial with `i32` coefficients at an `i64` input, accumulating in `i64`.
+///
+/// Each coefficient is widened to `i64` as it is read, and the polynomial is evaluated with Horner's method,
+/// such as for polynomial hashes and checksums. As with [`poly_quantized`], intermediate results wrap on
+/// overflow, so the result is the exact value modulo 2⁶⁴, which is usually what a hash requires.
+/// Use [`poly_widen_i32_checked`] to detect overflow instead.
+///
+/// ```rust
+/// use fast_polynomial::poly_widen_i32;
+///
+/// assert_eq!(poly_widen_i32(1 << 20, &[i32::MAX, -1, 3]), i32::MAX as i64 - (1 << 20) + (3 << 40));
+/// ```
+#[inline]
+pub fn poly_widen_i32(x: i64, coeffs: &[i32]) -> i64 {
+    coeffs
+        .iter()
+        .rev()
+        .fold(0i64, |sum, &c| sum.wrapping_mul(x).wrapping_add(c as i64))
 }
 
-/// Evaluate a polynomial for a slice of coefficients. May not be monomorphized.
+/// Evaluate a polynomial with `i32` coefficients at an `i64` input, returning [`PolyError::Overflow`]
+/// if any intermediate result overflows an `i64`.
 ///
-/// To not be monomorphized means this function's codegen may be used for any number of coefficients,
-/// and therefore contains branches. It will be faster to use [`poly_array`] instead if possible.
-pub fn poly<F: PolyNum>(x: F, coeffs: &[F]) -> F {
-    poly_f_internal::<F, _, 0>(x, coeffs.len(), |i| unsafe { *coeffs.get_unchecked(i) })
+/// Otherwise, this is identical to [`poly_widen_i32`], which instead wraps on overflow.
+///
+/// ```rust
+/// use fast_polynomial::{poly_widen_i32_checked, PolyError};
+///
+/// assert_eq!(poly_widen_i32_checked(-7, &[1, 2, 3]), Ok(1 - 14 + 147));
+/// assert_eq!(poly_widen_i32_checked(1 << 40, &[0, 0, 1]), Err(PolyError::Overflow));
+/// ```
+#[inline]
+pub fn poly_widen_i32_checked(x: i64, coeffs: &[i32]) -> Result<i64, PolyError> {
+    coeffs.iter().rev().try_fold(0i64, |sum, &c| {
+        sum.checked_mul(x)
+            .and_then(|sum| sum.checked_add(c as i64))
+            .ok_or(PolyError::Overflow)
+    })
 }
 
-/// Evaluate a rational polynomial for an array of coefficients. May not be monomorphized.
+/// Evaluate a polynomial in signed fixed-point arithmetic, with `x` and the coefficients in `Qn.m` format
+/// with `m = frac_bits` fractional bits, returning the result in the same format.
 ///
-/// To not be monomorphized means this function's codegen may be used for any number of coefficients,
-/// and therefore contains branches. It will be faster to use [`rational_array`] instead if possible.
-pub fn rational<F: PolyRational>(x: F, numerator: &[F], denominator: &[F]) -> F {
-    rational_f_internal::<F, _, _, 0, 0>(
-        x,
-        numerator.len(),
-        denominator.len(),
-        |i| unsafe { *numerator.get_unchecked(i) },
-        |i| unsafe { *denominator.get_unchecked(i) },
-    )
+/// The polynomial is evaluated with Horner's method, where each step multiplies the running sum by `x` into an `i64`,
+/// which cannot overflow, then shifts it right by `frac_bits` before adding the next coefficient:
+///
+/// ```text
+/// sum = ((sum * x) >> frac_bits) + c_k
+/// ```
+///
+/// The shift is arithmetic, so rounds toward negative infinity, and the result of each step wraps to an `i32` on overflow.
+/// As only integer operations with fully specified results are used, the output is bit-identical on every platform,
+/// unlike the floating-point routines, such as for procedural generation or lockstep simulations.
+///
+/// ```rust
+/// use fast_polynomial::poly_fixed;
+///
+/// // 1 + 2x + 0.25x^2 at x = 0.5 in Q16.16, which is 2.0625
+/// let y = poly_fixed(1 << 15, &[1 << 16, 2 << 16, 1 << 14], 16);
+///
+/// assert_eq!(y, 135168);
+/// assert_eq!(y as f64 / 65536.0, 2.0625);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `frac_bits >= 32`.
+#[inline]
+pub fn poly_fixed(x: i32, coeffs: &[i32], frac_bits: u32) -> i32 {
+    assert!(
+        frac_bits < 32,
+        "frac_bits must be less than 32, found {frac_bits}"
+    );
+
+    let x = x as i64;
+
+    coeffs.iter().rev().fold(0i32, |sum, &c| {
+        (((sum as i64 * x) >> frac_bits) as i32).wrapping_add(c)
+    })
 }
 
 /// Evaluate a polynomial using a function to provide coefficients.
@@ -156,6 +1811,133 @@ where
     poly_f_internal::<F, _, 0>(x, n, g)
 }
 
+/// Evaluate a member of a parameterized family of polynomials, `p_t(x) = Σ g(k, t) x^k` for `k` in `0..n`,
+/// using a function of both the index and the parameter `t` to provide coefficients.
+///
+/// This is equivalent to [`poly_f`] with a closure capturing `t`, but allows the same coefficient
+/// function to be reused for every `t`. The coefficients are requested in the same order as [`poly_f`],
+/// each exactly once, and `t` is passed through unchanged, so it need not be related to `x` at all.
+///
+/// For example, a bivariate polynomial can be evaluated by treating the coefficients of `x^k` as polynomials in `t`:
+///
+/// ```rust
+/// use fast_polynomial::{poly, poly_f2};
+///
+/// // p(x, t) = (1 + t) + (2 - t^2) x + 3t x^2
+/// let rows: [&[f64]; 3] = [&[1.0, 1.0], &[2.0, 0.0, -1.0], &[0.0, 3.0]];
+/// let p = |x: f64, t: f64| poly_f2(x, t, rows.len(), |k, t| poly(t, rows[k]));
+///
+/// assert_eq!(p(2.0, 0.5), 1.5 + 1.75 * 2.0 + 1.5 * 4.0);
+/// ```
+#[inline]
+pub fn poly_f2<F: PolyNum, G>(x: F, t: F, n: usize, mut g: G) -> F
+where
+    G: FnMut(usize, F) -> F,
+{
+    poly_f_internal::<F, _, 0>(x, n, |k| g(k, t))
+}
+
+/// Evaluate a "polynomial" with Horner's method using the given multiplication and addition operations.
+///
+/// This allows the same evaluation structure to be used for any semiring, rather than only numbers
+/// implementing [`PolyNum`]. For example, in the tropical (min-plus) semiring, where addition is `min`
+/// and multiplication is `+`, this computes `min_k(c_k + k * x)`:
+///
+/// ```rust
+/// use fast_polynomial::poly_fold;
+///
+/// let y = poly_fold(2.0f64, &[5.0, 1.0, 0.5], |a, b| a + b, f64::min);
+///
+/// assert_eq!(y, Some(3.0)); // min(5.0, 1.0 + 2.0, 0.5 + 4.0)
+/// ```
+///
+/// Because there is no general zero value, this returns `None` if there are no coefficients.
+/// Unlike the other evaluation routines, no attempt is made to reorder operations with Estrin's scheme,
+/// as the operations may not be associative or distributive, and only `mul(sum, x)` is ever computed.
+#[inline]
+pub fn poly_fold<F: Copy, M, A>(x: F, coeffs: &[F], mut mul: M, mut add: A) -> Option<F>
+where
+    M: FnMut(F, F) -> F,
+    A: FnMut(F, F) -> F,
+{
+    let (&last, rest) = coeffs.split_last()?;
+
+    let mut sum = last;
+
+    for &c in rest.iter().rev() {
+        sum = add(mul(sum, x), c);
+    }
+
+    Some(sum)
+}
+
+/// Evaluate a polynomial using a fallible function to provide coefficients.
+///
+/// Equivalent to [`poly_f`], but short-circuits on the first error produced by `g`, returning it without
+/// requesting any further coefficients or evaluating any further terms. Coefficients are requested in blocks
+/// of 16 starting from the highest degree, each block in order of increasing degree, and are evaluated
+/// in the same way as [`poly_slice_scratch`]. Without the `multi-accumulator` feature, the result is identical
+/// to [`poly_f`].
+///
+/// ```rust
+/// use fast_polynomial::try_poly_f;
+///
+/// let c = [1.0, 0.3, 0.4, 1.6];
+///
+/// let y: Result<f64, ()> = try_poly_f(0.5, 4, |i| Ok(c[i]));
+/// assert!((y.unwrap() - 1.45).abs() < 1e-12);
+///
+/// let y = try_poly_f(0.5, 5, |i| c.get(i).copied().ok_or(i));
+/// assert_eq!(y, Err(4));
+/// ```
+#[inline]
+pub fn try_poly_f<F: PolyNum, E, G>(x: F, n: usize, mut g: G) -> Result<F, E>
+where
+    G: FnMut(usize) -> Result<F, E>,
+{
+    let mut block = [F::zero(); 16];
+
+    let mut fill = |block: &mut [F; 16], start: usize, len: usize| {
+        for (i, c) in block[..len].iter_mut().enumerate() {
+            *c = g(start + i)?;
+        }
+
+        Ok(())
+    };
+
+    if n <= 16 {
+        fill(&mut block, 0, n)?;
+
+        return Ok(poly_f_internal::<F, _, 0>(x, n, |i| unsafe {
+            *block.get_unchecked(i)
+        }));
+    }
+
+    // the highest blocks are full, leaving any remainder in the lowest, as in `poly_slice_scratch`
+    let p = Powers::new(x);
+    let r = n % 16;
+    let mut j = n - 16;
+
+    fill(&mut block, j, 16)?;
+    let mut sum = poly_array_powers::<F, 16>(&p, &block);
+
+    while j > r {
+        j -= 16;
+
+        fill(&mut block, j, 16)?;
+        sum = fma(p.x16, sum, poly_array_powers::<F, 16>(&p, &block));
+    }
+
+    if r == 0 {
+        return Ok(sum);
+    }
+
+    fill(&mut block, 0, r)?;
+    let res = poly_f_powers_internal::<F, _, _, 0>(p, r, |i| unsafe { *block.get_unchecked(i) });
+
+    Ok(fma(tail_power_dyn(r, p.x, p.x2, p.x4, p.x8), sum, res))
+}
+
 /// Evaluate a rational polynomial using a function to provide coefficients.
 ///
 /// To preserve numerical stability, the rational polynomial is evaluated using the reciprocal of the input
@@ -164,6 +1946,9 @@ where
 /// helps keep the powers of `x` in the polynomial within -1 and 1, which is important for
 /// numerical stability.
 ///
+/// If `x` cannot be compared to one, such as when `x` is NaN, the polynomial is always evaluated
+/// without the reciprocal. For floating-point types, a NaN input will therefore always produce a NaN output.
+///
 /// This function is more flexible than [`rational`] as it allows for the coefficients to be
 /// generated on-the-fly. This can be useful for generating coefficients that are not
 /// known at compile-time. However, this function may be slower than [`rational`] due to the
@@ -177,6 +1962,86 @@ where
     rational_f_internal::<F, _, _, 0, 0>(x, p, q, numerator, denomiator)
 }
 
+/// Evaluate a rational polynomial using a function to provide coefficients, using the reciprocal of `x`
+/// if its magnitude is greater than `threshold`, rather than one as with [`rational_f`].
+///
+/// Evaluating with the reciprocal `z = 1/x` is an exact identity for any non-zero `x`, including the correction
+/// by `x^(p-q)` or `z^(q-p)` when the degrees differ, so moving the threshold never changes the function that is evaluated,
+/// and the two forms agree on either side of it up to rounding. What the threshold controls is which powers may grow:
+/// with the forward form, `|x|^k` grows for inputs above one, while with the reciprocal form, `|z|^k` and the degree
+/// correction grow for inputs below one. A threshold above one therefore risks overflow in the forward form for
+/// the inputs between one and the threshold, and a threshold below one in the reciprocal form for those between the
+/// threshold and one, in both cases even where the result itself is finite. Within that range, choose the threshold
+/// where the rounding errors of the two forms are comparable, such as the boundary of the domain the approximant
+/// was constructed on, to minimize the jump in error as the threshold is crossed.
+///
+/// Only the magnitude of `threshold` is used, and zero is never inverted. If `x` or `threshold` cannot be compared,
+/// such as when either is NaN, the polynomial is evaluated without the reciprocal.
+///
+/// ```rust
+/// use fast_polynomial::{rational, rational_f_threshold};
+///
+/// let n = [1.0f64, 2.0, 3.0, 4.0];
+/// let d = [2.0f64, 1.0, 5.0];
+///
+/// let r = |x: f64, t: f64| rational_f_threshold(x, n.len(), d.len(), |i| n[i], |i| d[i], t);
+///
+/// for x in [0.5, 1.5, 3.0] {
+///     assert!((r(x, 2.0) - rational(x, &n, &d)).abs() < 1e-15 * rational(x, &n, &d).abs());
+/// }
+/// ```
+#[inline]
+pub fn rational_f_threshold<F: PolyRational, N, D>(
+    x: F,
+    p: usize,
+    q: usize,
+    numerator: N,
+    denominator: D,
+    threshold: F,
+) -> F
+where
+    N: FnMut(usize) -> F,
+    D: FnMut(usize) -> F,
+{
+    rational_f_invert::<F, _, _, _, 0, 0>(x, p, q, numerator, denominator, |x| {
+        exceeds(x, threshold)
+    })
+}
+
+/// Evaluate a rational polynomial for an array of coefficients, using the reciprocal of `x` if its magnitude
+/// is greater than `threshold`, rather than one as with [`rational_array`]. Can be monomorphized.
+///
+/// See [`rational_f_threshold`] for how the threshold affects the evaluation.
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+pub fn rational_array_threshold<F: PolyRational, const P: usize, const Q: usize>(
+    x: F,
+    numerator: &[F; P],
+    denominator: &[F; Q],
+    threshold: F,
+) -> F {
+    rational_f_invert::<F, _, _, _, P, Q>(
+        x,
+        P,
+        Q,
+        |i| unsafe { *numerator.get_unchecked(i) },
+        |i| unsafe { *denominator.get_unchecked(i) },
+        |x| exceeds(x, threshold),
+    )
+}
+
+/// Whether the magnitude of `x` is greater than that of `threshold`, without requiring `abs`.
+#[inline(always)]
+fn exceeds<F: PolyRational>(x: F, threshold: F) -> bool {
+    let t = if threshold < F::zero() {
+        -threshold
+    } else {
+        threshold
+    };
+
+    x > t || x < -t
+}
+
 /// Variation of [`poly_f`] that is monomorphized for a specific number of coefficients.
 #[inline]
 pub fn poly_f_n<F: PolyNum, G, const N: usize>(x: F, g: G) -> F
@@ -200,27 +2065,53 @@ where
     rational_f_internal::<F, _, _, P, Q>(x, P, Q, numerator, denomiator)
 }
 
-#[rustfmt::skip]
-#[inline(always)]
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
 fn rational_f_internal<F: PolyRational, N, D, const P: usize, const Q: usize>(
+    x: F,
+    p: usize,
+    q: usize,
+    numerator: N,
+    denominator: D,
+) -> F
+where
+    N: FnMut(usize) -> F,
+    D: FnMut(usize) -> F,
+{
+    // Compare the magnitude rather than `x * x`, which could overflow for very large `x`.
+    //
+    // Both comparisons are false for unordered values (such as when `x` is NaN), which explicitly take
+    // the non-inverted path, so for floats the NaN simply propagates through the forward evaluation.
+    rational_f_invert::<F, _, _, _, P, Q>(x, p, q, numerator, denominator, |x| {
+        let one = F::one();
+
+        x > one || x < -one
+    })
+}
+
+/// [`rational_f_internal`] with a custom test for `|x| > 1`, deciding whether to evaluate with the reciprocal.
+#[rustfmt::skip]
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+fn rational_f_invert<F: PolyRational, N, D, I, const P: usize, const Q: usize>(
     x: F,
     p: usize,
     q: usize,
     mut numerator: N,
     mut denominator: D,
+    invert: I,
 ) -> F
 where
     N: FnMut(usize) -> F,
     D: FnMut(usize) -> F,
+    I: FnOnce(F) -> bool,
 {
     let one = F::one();
 
     // static or dynamic degree checks
     let high_degree = (P > 2 || Q > 2) || (P == 0 && Q == 0 && (p > 2 || q > 2));
 
-    // if the length is greater than 2 (degree >= 2) the multiplication will be performed
-    // anyway, and LLVM will reuse this result for the non-inverted polynomial below.
-    if high_degree && (x * x) > one {
+    if high_degree && invert(x) {
         if P > 0 { unsafe { assume(p == P) } }
         if Q > 0 { unsafe { assume(q == Q) } }
 
@@ -283,14 +2174,97 @@ where
     }
 }
 
-#[inline(always)]
+/// Source of the powers of `x` used by [`poly_f_powers_internal`], either computed where they're
+/// needed, or taken from a [`Powers`] computed beforehand.
+///
+/// Each power is given the previous one, so that it is only ever computed once, without relying on
+/// the optimizer to merge repeated multiplications, which it cannot do for types such as tape nodes.
+trait PowerSource<F> {
+    fn x(&self) -> F;
+    fn x2(&self) -> F;
+    fn x4(&self, x2: F) -> F;
+    fn x8(&self, x4: F) -> F;
+    fn x16(&self, x8: F) -> F;
+}
+
+/// Computes each power from the last.
+struct LazyPowers<F>(F);
+
+impl<F: PolyNum> PowerSource<F> for LazyPowers<F> {
+    #[inline(always)]
+    fn x(&self) -> F {
+        self.0
+    }
+
+    #[inline(always)]
+    fn x2(&self) -> F {
+        self.0 * self.0
+    }
+
+    #[inline(always)]
+    fn x4(&self, x2: F) -> F {
+        x2 * x2
+    }
+
+    #[inline(always)]
+    fn x8(&self, x4: F) -> F {
+        x4 * x4
+    }
+
+    #[inline(always)]
+    fn x16(&self, x8: F) -> F {
+        x8 * x8
+    }
+}
+
+impl<F: PolyNum> PowerSource<F> for Powers<F> {
+    #[inline(always)]
+    fn x(&self) -> F {
+        self.x
+    }
+
+    #[inline(always)]
+    fn x2(&self) -> F {
+        self.x2
+    }
+
+    #[inline(always)]
+    fn x4(&self, _: F) -> F {
+        self.x4
+    }
+
+    #[inline(always)]
+    fn x8(&self, _: F) -> F {
+        self.x8
+    }
+
+    #[inline(always)]
+    fn x16(&self, _: F) -> F {
+        self.x16
+    }
+}
+
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+fn poly_f_internal<F: PolyNum, G, const LENGTH: usize>(x: F, n: usize, g: G) -> F
+where
+    G: FnMut(usize) -> F,
+{
+    poly_f_powers_internal::<F, _, G, LENGTH>(LazyPowers(x), n, g)
+}
+
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
 #[rustfmt::skip]
-fn poly_f_internal<F: PolyNum, G, const LENGTH: usize>(x: F, n: usize, mut g: G) -> F
+fn poly_f_powers_internal<F: PolyNum, P, G, const LENGTH: usize>(p: P, n: usize, mut g: G) -> F
 where
+    P: PowerSource<F>,
     G: FnMut(usize) -> F,
 {
     use polynomials::*;
 
+    let x = p.x();
+
     if LENGTH > 0 {
         unsafe { assume(n == LENGTH) };
     }
@@ -306,20 +2280,25 @@ where
         0 => return F::zero(),
         1 => return g(0),
         2 => return poly!(poly_1(x;        {0} + g[0, 1])),
-        3 => return poly!(poly_2(x, x * x; {0} + g[0, 1, 2])),
-        4 => return poly!(poly_3(x, x * x; {0} + g[0, 1, 2, 3])),
+        3 => return poly!(poly_2(x, p.x2(); {0} + g[0, 1, 2])),
+        4 => return poly!(poly_3(x, p.x2(); {0} + g[0, 1, 2, 3])),
         _ => {}
     }
 
-    let x2 = x * x;
-    let x4 = x2 * x2;
-    let x8 = x4 * x4;
+    let x2 = p.x2();
+    let x4 = p.x4(x2);
 
     match n {
         5 =>  return poly!(poly_4 (x, x2, x4;     {0} + g[0, 1, 2, 3, 4])),
         6 =>  return poly!(poly_5 (x, x2, x4;     {0} + g[0, 1, 2, 3, 4, 5])),
         7 =>  return poly!(poly_6 (x, x2, x4;     {0} + g[0, 1, 2, 3, 4, 5, 6])),
         8 =>  return poly!(poly_7 (x, x2, x4;     {0} + g[0, 1, 2, 3, 4, 5, 6, 7])),
+        _ => {}
+    }
+
+    let x8 = p.x8(x4);
+
+    match n {
         9 =>  return poly!(poly_8 (x, x2, x4, x8; {0} + g[0, 1, 2, 3, 4, 5, 6, 7, 8])),
         10 => return poly!(poly_9 (x, x2, x4, x8; {0} + g[0, 1, 2, 3, 4, 5, 6, 7, 8, 9])),
         11 => return poly!(poly_10(x, x2, x4, x8; {0} + g[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10])),
@@ -331,39 +2310,156 @@ where
         _ => {}
     }
 
-    let x16 = x8 * x8;
-
-    let mut sum = F::zero();
-
     // Use a hybrid Estrin/Horner algorithm
     let mut j = n;
-    while j >= 16 {
+
+    // The accumulators are initialized with the first block directly, rather than zero, so that
+    // the result is the same as the fast paths above when the sum is a signed zero, and so that
+    // an infinite power of x cannot turn the unused zero sum into NaN.
+
+    // Split the blocks between 4 independent accumulators to shorten the dependency chain,
+    // then recombine them and continue with the serial loop for any remaining blocks.
+    #[cfg(feature = "multi-accumulator")]
+    let (mut sum, x16) = if j >= 64 {
+        let x16 = p.x16(x8);
+        let x32 = x16 * x16;
+
+        j -= 64;
+        let mut s3 = poly!(poly_15(x, x2, x4, x8; { j + 48 } + g[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]));
+        let mut s2 = poly!(poly_15(x, x2, x4, x8; { j + 32 } + g[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]));
+        let mut s1 = poly!(poly_15(x, x2, x4, x8; { j + 16 } + g[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]));
+        let mut s0 = poly!(poly_15(x, x2, x4, x8; { j      } + g[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15]));
+
+        if j >= 64 {
+            let x64 = x32 * x32;
+
+            while j >= 64 {
+                j -= 64;
+                s3 = fma(x64, s3, poly!(poly_15(x, x2, x4, x8; { j + 48 } + g[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15])));
+                s2 = fma(x64, s2, poly!(poly_15(x, x2, x4, x8; { j + 32 } + g[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15])));
+                s1 = fma(x64, s1, poly!(poly_15(x, x2, x4, x8; { j + 16 } + g[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15])));
+                s0 = fma(x64, s0, poly!(poly_15(x, x2, x4, x8; { j      } + g[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15])));
+            }
+        }
+
+        (fma(x32, fma(x16, s3, s2), fma(x16, s1, s0)), Some(x16))
+    } else {
         j -= 16;
-        sum = fma(sum, x16, poly!(poly_15(x, x2, x4, x8; { j } + g[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15])));
+        (poly!(poly_15(x, x2, x4, x8; { j } + g[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15])), None)
+    };
+
+    // n > 16 here, so there is always at least one full block
+    #[cfg(not(feature = "multi-accumulator"))]
+    let (mut sum, x16) = {
+        j -= 16;
+        (poly!(poly_15(x, x2, x4, x8; { j } + g[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15])), None)
+    };
+
+    // x^16 is only needed if there are further full blocks
+    if j >= 16 {
+        let x16 = x16.unwrap_or_else(|| p.x16(x8));
+
+        while j >= 16 {
+            j -= 16;
+            sum = fma(x16, sum, poly!(poly_15(x, x2, x4, x8; { j } + g[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15])));
+        }
     }
 
     // handle remaining powers
     let (rmx, res) = match j {
         0  => return sum,
-        1  => (x, g(0)),
-        2  => (x2,             poly!(poly_1 (x;             {0} + g[0, 1]))),
-        3  => (x2*x,           poly!(poly_2 (x, x2;         {0} + g[0, 1, 2]))),
-        4  => (x4,             poly!(poly_3 (x, x2;         {0} + g[0, 1, 2, 3]))),
-        5  => (x4*x,           poly!(poly_4 (x, x2, x4;     {0} + g[0, 1, 2, 3, 4]))),
-        6  => (x4*x2,          poly!(poly_5 (x, x2, x4;     {0} + g[0, 1, 2, 3, 4, 5]))),
-        7  => (x4*x2*x,        poly!(poly_6 (x, x2, x4;     {0} + g[0, 1, 2, 3, 4, 5, 6]))),
-        8  => (x8,             poly!(poly_7 (x, x2, x4;     {0} + g[0, 1, 2, 3, 4, 5, 6, 7]))),
-        9  => (x8*x,           poly!(poly_8 (x, x2, x4, x8; {0} + g[0, 1, 2, 3, 4, 5, 6, 7, 8]))),
-        10 => (x8*x2,          poly!(poly_9 (x, x2, x4, x8; {0} + g[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]))),
-        11 => (x8*x2*x,        poly!(poly_10(x, x2, x4, x8; {0} + g[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10]))),
-        12 => (x8*x4,          poly!(poly_11(x, x2, x4, x8; {0} + g[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]))),
-        13 => (x8*x4*x,        poly!(poly_12(x, x2, x4, x8; {0} + g[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]))),
-        14 => (x8*x4*x2,       poly!(poly_13(x, x2, x4, x8; {0} + g[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13]))),
-        15 => ((x8*x4)*(x2*x), poly!(poly_14(x, x2, x4, x8; {0} + g[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14]))),
-        _  => unsafe { core::hint::unreachable_unchecked() }
+        1  => (tail_power::<F, 1> (x, x2, x4, x8), g(0)),
+        2  => (tail_power::<F, 2> (x, x2, x4, x8), poly!(poly_1 (x;             {0} + g[0, 1]))),
+        3  => (tail_power::<F, 3> (x, x2, x4, x8), poly!(poly_2 (x, x2;         {0} + g[0, 1, 2]))),
+        4  => (tail_power::<F, 4> (x, x2, x4, x8), poly!(poly_3 (x, x2;         {0} + g[0, 1, 2, 3]))),
+        5  => (tail_power::<F, 5> (x, x2, x4, x8), poly!(poly_4 (x, x2, x4;     {0} + g[0, 1, 2, 3, 4]))),
+        6  => (tail_power::<F, 6> (x, x2, x4, x8), poly!(poly_5 (x, x2, x4;     {0} + g[0, 1, 2, 3, 4, 5]))),
+        7  => (tail_power::<F, 7> (x, x2, x4, x8), poly!(poly_6 (x, x2, x4;     {0} + g[0, 1, 2, 3, 4, 5, 6]))),
+        8  => (tail_power::<F, 8> (x, x2, x4, x8), poly!(poly_7 (x, x2, x4;     {0} + g[0, 1, 2, 3, 4, 5, 6, 7]))),
+        9  => (tail_power::<F, 9> (x, x2, x4, x8), poly!(poly_8 (x, x2, x4, x8; {0} + g[0, 1, 2, 3, 4, 5, 6, 7, 8]))),
+        10 => (tail_power::<F, 10>(x, x2, x4, x8), poly!(poly_9 (x, x2, x4, x8; {0} + g[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]))),
+        11 => (tail_power::<F, 11>(x, x2, x4, x8), poly!(poly_10(x, x2, x4, x8; {0} + g[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10]))),
+        12 => (tail_power::<F, 12>(x, x2, x4, x8), poly!(poly_11(x, x2, x4, x8; {0} + g[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]))),
+        13 => (tail_power::<F, 13>(x, x2, x4, x8), poly!(poly_12(x, x2, x4, x8; {0} + g[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]))),
+        14 => (tail_power::<F, 14>(x, x2, x4, x8), poly!(poly_13(x, x2, x4, x8; {0} + g[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13]))),
+        15 => (tail_power::<F, 15>(x, x2, x4, x8), poly!(poly_14(x, x2, x4, x8; {0} + g[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14]))),
+        _  => unsafe { unreachable() }
     };
 
-    fma(sum, rmx, res)
+    fma(rmx, sum, res)
+}
+
+/// Which of `x`, `x²`, `x⁴` and `x⁸` multiply to give `x^j`, which is simply the binary representation of `j`.
+const fn tail_factors(j: usize) -> [bool; 4] {
+    [j & 1 != 0, j & 2 != 0, j & 4 != 0, j & 8 != 0]
+}
+
+/// [`tail_factors`] for every remainder of the hybrid loop.
+const TAIL_FACTORS: [[bool; 4]; 16] = {
+    let mut table = [[false; 4]; 16];
+    let mut j = 0;
+
+    while j < 16 {
+        table[j] = tail_factors(j);
+        j += 1;
+    }
+
+    table
+};
+
+/// `x^J` for `0 < J < 16`, from the factors in [`TAIL_FACTORS`]. The two highest factors and any remaining
+/// factors are multiplied independently before being combined, to shorten the dependency chain.
+///
+/// `J` is a constant so that the factors are always known at compile-time, and the product folds to the same
+/// multiplications as writing it out by hand.
+#[inline(always)]
+fn tail_power<F: PolyNum, const J: usize>(x: F, x2: F, x4: F, x8: F) -> F {
+    let [b0, b1, b2, b3] = TAIL_FACTORS[J];
+
+    let (mut hi, mut lo, mut count) = (None, None, 0);
+
+    for (b, factor) in [(b3, x8), (b2, x4), (b1, x2), (b0, x)] {
+        if b {
+            let acc = if count < 2 { &mut hi } else { &mut lo };
+
+            *acc = Some(match *acc {
+                Some(acc) => acc * factor,
+                None => factor,
+            });
+
+            count += 1;
+        }
+    }
+
+    match (hi, lo) {
+        (Some(hi), Some(lo)) => hi * lo,
+        (Some(hi), None) => hi,
+        _ => unsafe { unreachable() },
+    }
+}
+
+/// [`tail_power`] for a remainder `0 < j < 16` only known at runtime, dispatching to a constant `J`.
+#[rustfmt::skip]
+#[inline(always)]
+fn tail_power_dyn<F: PolyNum>(j: usize, x: F, x2: F, x4: F, x8: F) -> F {
+    match j {
+        1  => tail_power::<F, 1> (x, x2, x4, x8),
+        2  => tail_power::<F, 2> (x, x2, x4, x8),
+        3  => tail_power::<F, 3> (x, x2, x4, x8),
+        4  => tail_power::<F, 4> (x, x2, x4, x8),
+        5  => tail_power::<F, 5> (x, x2, x4, x8),
+        6  => tail_power::<F, 6> (x, x2, x4, x8),
+        7  => tail_power::<F, 7> (x, x2, x4, x8),
+        8  => tail_power::<F, 8> (x, x2, x4, x8),
+        9  => tail_power::<F, 9> (x, x2, x4, x8),
+        10 => tail_power::<F, 10>(x, x2, x4, x8),
+        11 => tail_power::<F, 11>(x, x2, x4, x8),
+        12 => tail_power::<F, 12>(x, x2, x4, x8),
+        13 => tail_power::<F, 13>(x, x2, x4, x8),
+        14 => tail_power::<F, 14>(x, x2, x4, x8),
+        15 => tail_power::<F, 15>(x, x2, x4, x8),
+        _  => unsafe { unreachable() }
+    }
 }
 
 #[inline(always)]
@@ -376,9 +2472,27 @@ fn likely(b: bool) -> bool {
     if !b { cold() } b
 }
 
+/// Optimization hint that `cond` is true.
+///
+/// With debug assertions or the `paranoid` feature enabled, this is a real assertion instead,
+/// so that violated invariants panic rather than causing undefined behavior.
 #[inline(always)]
 unsafe fn assume(cond: bool) {
+    #[cfg(any(debug_assertions, feature = "paranoid"))]
+    assert!(cond, "fast_polynomial: internal invariant violated");
+
+    #[cfg(not(any(debug_assertions, feature = "paranoid")))]
     if !cond {
         core::hint::unreachable_unchecked();
     }
 }
+
+/// Optimization hint that this code is unreachable, checked in the same way as [`assume`].
+#[inline(always)]
+unsafe fn unreachable() -> ! {
+    #[cfg(any(debug_assertions, feature = "paranoid"))]
+    unreachable!("fast_polynomial: internal invariant violated");
+
+    #[cfg(not(any(debug_assertions, feature = "paranoid")))]
+    core::hint::unreachable_unchecked()
+}
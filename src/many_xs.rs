@@ -0,0 +1,774 @@
+//! Evaluate many polynomials, or many values of `x`, at once.
+//!
+//! [`ArrayWrap`] is a simple fixed-size vector type implementing [`PolyNum`](crate::PolyNum)
+//! with element-wise arithmetic. Because every lane is independent, the optimizer is free to
+//! vectorize the evaluation when the target supports it, without requiring `portable_simd`
+//! or any particular SIMD crate. Beyond the evaluators of this crate, it also provides the full set of
+//! arithmetic operators and lane-wise [`abs`](ArrayWrap::abs), [`min`](ArrayWrap::min) and [`max`](ArrayWrap::max),
+//! so it can stand in for a small SIMD vector in other algorithms.
+//!
+//! To evaluate one polynomial for many values of `x`, wrap the inputs and splat the coefficients:
+//!
+//! ```rust
+//! use fast_polynomial::{poly_array, many_xs::ArrayWrap};
+//!
+//! let xs = ArrayWrap([0.0f32, 0.25, 0.5, 0.75]);
+//! let c = [1.0, 0.3, 0.4, 1.6].map(ArrayWrap::splat);
+//!
+//! let ys = poly_array(xs, &c);
+//! # assert!((ys.0[2] - 1.45).abs() < 1e-6);
+//! ```
+//!
+//! Conversely, to evaluate a _family_ of polynomials at a single `x`, store each coefficient
+//! as a lane-wise vector of the coefficients of every polynomial, and splat `x` instead.
+//! Here, 8 different cubic polynomials are evaluated at once:
+//!
+//! ```rust
+//! use fast_polynomial::{poly_array, many_xs::ArrayWrap};
+//!
+//! // coefficients[k].0[i] is the coefficient of x^k for polynomial i
+//! let coefficients: [ArrayWrap<8, f32>; 4] = [
+//!     ArrayWrap([1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0]),
+//!     ArrayWrap([0.3; 8]),
+//!     ArrayWrap([0.4, 0.0, 0.4, 0.0, 0.4, 0.0, 0.4, 0.0]),
+//!     ArrayWrap([1.6; 8]),
+//! ];
+//!
+//! let x = 0.5f32;
+//! let ys = poly_array(ArrayWrap::splat(x), &coefficients);
+//!
+//! for i in 0..8 {
+//!     let c = |k: usize| coefficients[k].0[i];
+//!     let expected = c(0) + x * (c(1) + x * (c(2) + x * c(3)));
+//!     assert!((ys.0[i] - expected).abs() < 1e-6);
+//! }
+//! ```
+//!
+//! Tuples such as `(f32, f32)` cannot implement [`PolyNum`](crate::PolyNum), as the arithmetic traits
+//! are foreign to both this crate and yours. For tiny batches, `ArrayWrap` converts to and from tuples
+//! of two to four values instead:
+//!
+//! ```rust
+//! use fast_polynomial::{poly_array, many_xs::ArrayWrap};
+//!
+//! let c = [1.0, 0.3, 0.4, 1.6].map(ArrayWrap::splat);
+//! let (y0, y1): (f32, f32) = poly_array(ArrayWrap::from((0.0, 0.5)), &c).into();
+//! # assert!((y0 - 1.0).abs() < 1e-6 && (y1 - 1.45).abs() < 1e-6);
+//! ```
+
+use core::cmp::Ordering;
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+use num_traits::{MulAdd, One, Signed, Zero};
+
+use crate::{poly, poly_array, poly_f, rational_array, PolyNum, PolyRational};
+
+#[cfg(feature = "alloc")]
+use crate::PolyNumSigned;
+
+/// A fixed-size array of `N` values of `F`, with element-wise arithmetic.
+#[repr(transparent)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ArrayWrap<const N: usize, F>(pub [F; N]);
+
+impl<const N: usize, F: Copy> ArrayWrap<N, F> {
+    /// Create a new `ArrayWrap` with every lane set to `x`.
+    #[inline(always)]
+    pub fn splat(x: F) -> Self {
+        ArrayWrap([x; N])
+    }
+}
+
+impl<const N: usize, F: Copy> ArrayWrap<N, F> {
+    /// Gather lanes from arbitrary elements of `src`, such that lane `i` is `src[indices[i]]`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index is out of bounds for `src`.
+    #[inline]
+    pub fn gather(src: &[F], indices: &[usize; N]) -> Self {
+        ArrayWrap(core::array::from_fn(|i| src[indices[i]]))
+    }
+
+    /// Scatter lanes to arbitrary elements of `dst`, such that `dst[indices[i]]` is set to lane `i`.
+    ///
+    /// If an index is repeated, the element is set to the value of the last lane with that index.
+    ///
+    /// # Panics
+    ///
+    /// Panics if any index is out of bounds for `dst`. Lanes before the
+    /// first out-of-bounds index will have already been written.
+    #[inline]
+    pub fn scatter(&self, dst: &mut [F], indices: &[usize; N]) {
+        for (&x, &i) in self.0.iter().zip(indices) {
+            dst[i] = x;
+        }
+    }
+}
+
+impl<const N: usize, F> ArrayWrap<N, F> {
+    /// Borrow the lanes as an array.
+    #[inline(always)]
+    pub fn as_array(&self) -> &[F; N] {
+        &self.0
+    }
+
+    /// Mutably borrow the lanes as an array.
+    #[inline(always)]
+    pub fn as_mut_array(&mut self) -> &mut [F; N] {
+        &mut self.0
+    }
+
+    /// Reinterpret a reference to an array as a reference to an `ArrayWrap`, without copying.
+    #[inline(always)]
+    pub fn from_ref(x: &[F; N]) -> &Self {
+        // SAFETY: ArrayWrap is repr(transparent) over [F; N]
+        unsafe { &*(x as *const [F; N] as *const Self) }
+    }
+
+    /// Reinterpret a mutable reference to an array as a mutable reference to an `ArrayWrap`, without copying.
+    #[inline(always)]
+    pub fn from_mut(x: &mut [F; N]) -> &mut Self {
+        // SAFETY: ArrayWrap is repr(transparent) over [F; N]
+        unsafe { &mut *(x as *mut [F; N] as *mut Self) }
+    }
+}
+
+/// Lane-wise operations, allowing `ArrayWrap` to stand in for a small SIMD vector.
+impl<const N: usize, F: Copy> ArrayWrap<N, F> {
+    /// Apply `f` to every lane.
+    #[inline(always)]
+    pub fn map<G: Copy>(self, f: impl FnMut(F) -> G) -> ArrayWrap<N, G> {
+        ArrayWrap(self.0.map(f))
+    }
+
+    /// Compute the absolute value of every lane.
+    #[inline(always)]
+    pub fn abs(self) -> Self
+    where
+        F: Signed,
+    {
+        self.map(|x| x.abs())
+    }
+
+    /// Compute the minimum of each pair of lanes.
+    ///
+    /// Like [`f32::min`], if one of the lanes is NaN, the other is returned.
+    #[inline(always)]
+    pub fn min(self, other: Self) -> Self
+    where
+        F: PartialOrd,
+    {
+        self.select(other, Ordering::Greater)
+    }
+
+    /// Compute the maximum of each pair of lanes.
+    ///
+    /// Like [`f32::max`], if one of the lanes is NaN, the other is returned.
+    #[inline(always)]
+    pub fn max(self, other: Self) -> Self
+    where
+        F: PartialOrd,
+    {
+        self.select(other, Ordering::Less)
+    }
+
+    /// For each lane, select `other` if `self` compares as `ord` to it, or if `self` is unordered with itself.
+    #[inline(always)]
+    fn select(self, other: Self, ord: Ordering) -> Self
+    where
+        F: PartialOrd,
+    {
+        ArrayWrap(core::array::from_fn(|i| {
+            let (a, b) = (self.0[i], other.0[i]);
+
+            match a.partial_cmp(&b) {
+                Some(o) if o == ord => b,
+                None if a.partial_cmp(&a).is_none() => b,
+                _ => a,
+            }
+        }))
+    }
+}
+
+/// Horizontal reductions across all lanes.
+///
+/// Unlike the rest of the operations on [`ArrayWrap`], these intentionally break the element-wise
+/// abstraction by combining lanes together. They are the natural exit point from vectorized evaluation,
+/// such as summing the results of evaluating a polynomial at many points.
+impl<const N: usize, F: Copy> ArrayWrap<N, F> {
+    /// Sum all lanes together. Returns zero if `N == 0`.
+    #[inline(always)]
+    pub fn reduce_sum(&self) -> F
+    where
+        F: Zero,
+    {
+        self.0.iter().fold(F::zero(), |acc, &x| acc + x)
+    }
+
+    /// Multiply all lanes together. Returns one if `N == 0`.
+    #[inline(always)]
+    pub fn reduce_product(&self) -> F
+    where
+        F: One,
+    {
+        self.0.iter().fold(F::one(), |acc, &x| acc * x)
+    }
+
+    /// Find the maximum value of all lanes.
+    ///
+    /// Lanes that cannot be compared to the current maximum, such as NaN, are skipped,
+    /// unless the first lane is itself NaN.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N == 0`.
+    #[inline(always)]
+    pub fn reduce_max(&self) -> F
+    where
+        F: PartialOrd,
+    {
+        let mut max = self.0[0];
+        for &x in &self.0[1..] {
+            if x > max {
+                max = x;
+            }
+        }
+        max
+    }
+}
+
+/// Evaluate a rational polynomial for every lane of `x`, alongside a mask of which lanes
+/// had a denominator of exactly zero. Can be monomorphized.
+///
+/// Lanes flagged in the mask will typically be infinite or NaN, and can be fixed up afterwards
+/// without scanning the results. For near-zero denominators, evaluate the denominator separately
+/// with [`poly_array`] and compare it against a suitable threshold instead.
+///
+/// Unlike [`rational_array`], the input is never inverted for lanes where
+/// `|x| > 1`, as [`ArrayWrap`] has no ordering to decide this across all lanes at once. For large
+/// inputs, use [`rational_array_lanes`] instead, which decides this for each lane.
+///
+/// ```rust
+/// use fast_polynomial::many_xs::{rational_array_checked, ArrayWrap};
+///
+/// let xs = ArrayWrap([0.0f64, 1.0, 2.0, 3.0]);
+///
+/// // (1 + x) / (2 - x)
+/// let n = [1.0, 1.0].map(ArrayWrap::splat);
+/// let d = [2.0, -1.0].map(ArrayWrap::splat);
+///
+/// let (ys, zero) = rational_array_checked(xs, &n, &d);
+///
+/// assert_eq!(zero, [false, false, true, false]);
+/// assert_eq!(ys.0[1], 2.0);
+/// assert!(ys.0[2].is_infinite());
+/// ```
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+pub fn rational_array_checked<const N: usize, F, const P: usize, const Q: usize>(
+    x: ArrayWrap<N, F>,
+    numerator: &[ArrayWrap<N, F>; P],
+    denominator: &[ArrayWrap<N, F>; Q],
+) -> (ArrayWrap<N, F>, [bool; N])
+where
+    F: PolyNum + Div<Output = F>,
+{
+    let n = poly_array(x, numerator);
+    let d = poly_array(x, denominator);
+
+    (n / d, d.0.map(|d| d.is_zero()))
+}
+
+/// Evaluate a rational polynomial for every lane of `x`, using the reciprocal of each lane where `|x| > 1`,
+/// as with [`rational_array`]. Can be monomorphized.
+///
+/// Evaluating with the reciprocal reverses the order of the coefficients, so rather than evaluating both forms
+/// for every lane, the coefficients of each lane are selected according to whether that lane is inverted, and the
+/// polynomials are evaluated once at the lane-wise `x` or `1/x`. Inverted lanes are then corrected for any difference
+/// in degree individually. The result for each lane is identical to that of [`rational_array`].
+///
+/// ```rust
+/// use fast_polynomial::{rational_array, many_xs::{rational_array_lanes, ArrayWrap}};
+///
+/// let xs = ArrayWrap([0.5f64, -2.0, 1e200, -1e-200]);
+///
+/// let n = [1.0, 2.0, 3.0];
+/// let d = [4.0, 5.0];
+///
+/// let ys = rational_array_lanes(xs, &n, &d);
+///
+/// for (x, y) in xs.0.into_iter().zip(ys.0) {
+///     assert_eq!(y, rational_array(x, &n, &d));
+/// }
+/// ```
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
+pub fn rational_array_lanes<const N: usize, F: PolyRational, const P: usize, const Q: usize>(
+    x: ArrayWrap<N, F>,
+    numerator: &[F; P],
+    denominator: &[F; Q],
+) -> ArrayWrap<N, F> {
+    let one = F::one();
+
+    // as with rational_array, only polynomials with more than two coefficients are inverted
+    let invert = x.0.map(|x| (P > 2 || Q > 2) && (x > one || x < -one));
+
+    let w = ArrayWrap(core::array::from_fn(|i| match invert[i] {
+        true => one / x.0[i],
+        false => x.0[i],
+    }));
+
+    fn select<const N: usize, F: Copy, const K: usize>(
+        c: &[F; K],
+        invert: &[bool; N],
+    ) -> [ArrayWrap<N, F>; K] {
+        core::array::from_fn(|k| {
+            ArrayWrap(core::array::from_fn(|i| {
+                c[if invert[i] { K - 1 - k } else { k }]
+            }))
+        })
+    }
+
+    let mut res =
+        poly_array(w, &select(numerator, &invert)) / poly_array(w, &select(denominator, &invert));
+
+    if P != Q {
+        let lanes = res.0.iter_mut().zip(invert).zip(x.0.into_iter().zip(w.0));
+
+        for ((r, invert), (x, z)) in lanes {
+            if !invert {
+                continue;
+            }
+
+            // shift over the difference in degrees, in the same order as rational_array
+            let (mut u, mut e) = if P < Q { (z, Q - P) } else { (x, P - Q) };
+
+            loop {
+                if e & 1 != 0 {
+                    *r = *r * u;
+                }
+
+                e >>= 1;
+
+                if e == 0 {
+                    break;
+                }
+
+                u = u * u;
+            }
+        }
+    }
+
+    res
+}
+
+/// Evaluate a rational polynomial for arrays of coefficients at every value of `xs`, writing the results to `out`,
+/// processing `LANES` values at a time. Can be monomorphized.
+///
+/// Each chunk of `LANES` values is evaluated with [`rational_array_lanes`], with any remaining values evaluated
+/// individually with [`rational_array`], which gives identical results. As with
+/// [`poly_array_batch`], `LANES` should generally be chosen such that `ArrayWrap<LANES, F>` fills one or two
+/// SIMD registers of the target, such as applying a Padé approximant to every sample of a buffer.
+///
+/// ```rust
+/// use fast_polynomial::{rational_array, many_xs::rational_array_batch};
+///
+/// let xs: Vec<f64> = (0..100).map(|i| i as f64 / 10.0 - 5.0).collect();
+///
+/// // Padé approximant of exp(x)
+/// let n = [1.0, 0.5, 1.0 / 12.0];
+/// let d = [1.0, -0.5, 1.0 / 12.0];
+///
+/// let mut ys = vec![0.0; xs.len()];
+/// rational_array_batch::<4, _, 3, 3>(&xs, &n, &d, &mut ys);
+///
+/// for (&x, &y) in xs.iter().zip(&ys) {
+///     assert_eq!(y, rational_array(x, &n, &d));
+/// }
+/// ```
+///
+/// # Panics
+///
+/// Panics if `LANES` is zero, or if `out` is not the same length as `xs`.
+pub fn rational_array_batch<const LANES: usize, F: PolyRational, const P: usize, const Q: usize>(
+    xs: &[F],
+    numerator: &[F; P],
+    denominator: &[F; Q],
+    out: &mut [F],
+) {
+    assert!(LANES > 0, "lane count must be non-zero");
+    assert_eq!(
+        xs.len(),
+        out.len(),
+        "output must be the same length as the inputs"
+    );
+
+    let mut xs_chunks = xs.chunks_exact(LANES);
+    let mut out_chunks = out.chunks_exact_mut(LANES);
+
+    for (x, y) in (&mut xs_chunks).zip(&mut out_chunks) {
+        let x = ArrayWrap::<LANES, F>::from_ref(x.try_into().unwrap());
+        let y = ArrayWrap::<LANES, F>::from_mut(y.try_into().unwrap());
+
+        *y = rational_array_lanes(*x, numerator, denominator);
+    }
+
+    for (&x, y) in xs_chunks
+        .remainder()
+        .iter()
+        .zip(out_chunks.into_remainder())
+    {
+        *y = rational_array(x, numerator, denominator);
+    }
+}
+
+/// Evaluate a rational polynomial for arrays of coefficients at every value of `xs`, writing the results to `out`.
+/// Can be monomorphized.
+///
+/// This is [`rational_array_batch`] with 8 lanes, which fills one or two SIMD registers of most targets
+/// for `f32` and `f64`. Use [`rational_array_batch`] directly to choose the number of lanes.
+///
+/// ```rust
+/// use fast_polynomial::{rational_array, many_xs::rational_array_many};
+///
+/// let xs = [-2.0, -0.5, 0.0, 0.5, 2.0];
+/// let (n, d) = ([1.0, 0.5, 1.0 / 12.0], [1.0, -0.5, 1.0 / 12.0]);
+///
+/// let mut ys = [0.0; 5];
+/// rational_array_many(&xs, &n, &d, &mut ys);
+///
+/// assert_eq!(ys, xs.map(|x| rational_array(x, &n, &d)));
+/// ```
+///
+/// # Panics
+///
+/// Panics if `out` is not the same length as `xs`.
+#[inline]
+pub fn rational_array_many<F: PolyRational, const P: usize, const Q: usize>(
+    xs: &[F],
+    numerator: &[F; P],
+    denominator: &[F; Q],
+    out: &mut [F],
+) {
+    rational_array_batch::<8, F, P, Q>(xs, numerator, denominator, out)
+}
+
+/// Evaluate a polynomial at every value of `xs` and return the sum of the results, processing `N` values at a time.
+///
+/// Each chunk of `N` values is evaluated as an [`ArrayWrap`] and added to a running vector of sums, which is
+/// reduced to a single value at the end, with any remaining values evaluated individually. This fuses the
+/// evaluation with the reduction, avoiding storing the results to a buffer and loading them again, and
+/// unlike a sequential sum, allows the additions to be vectorized along with the evaluation.
+///
+/// This changes the order of the summation, so the result may differ slightly from summing sequentially.
+///
+/// ```rust
+/// use fast_polynomial::{poly, many_xs::poly_array_sum};
+///
+/// let xs: Vec<f64> = (0..100).map(|i| i as f64 / 100.0).collect();
+/// let c = [1.0, 0.3, 0.4, 1.6];
+///
+/// let sum = poly_array_sum::<8, _>(&xs, &c);
+/// let expected: f64 = xs.iter().map(|&x| poly(x, &c)).sum();
+///
+/// assert!((sum - expected).abs() < 1e-12);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `N` is zero.
+pub fn poly_array_sum<const N: usize, F: PolyNum>(xs: &[F], coeffs: &[F]) -> F {
+    assert!(N > 0, "lane count must be non-zero");
+
+    let chunks = xs.chunks_exact(N);
+    let remainder = chunks.remainder();
+
+    let mut sum = ArrayWrap::<N, F>::zero();
+
+    for chunk in chunks {
+        let x = ArrayWrap::from_ref(chunk.try_into().unwrap());
+
+        sum = sum
+            + poly_f(*x, coeffs.len(), |i| {
+                ArrayWrap::splat(unsafe { *coeffs.get_unchecked(i) })
+            });
+    }
+
+    remainder
+        .iter()
+        .fold(sum.reduce_sum(), |sum, &x| sum + poly(x, coeffs))
+}
+
+/// Evaluate a polynomial for an array of coefficients at every value of `xs`, writing the results to `out`,
+/// processing `LANES` values at a time. Can be monomorphized.
+///
+/// Each chunk of `LANES` values is evaluated as an [`ArrayWrap`] with [`poly_array`], with any remaining values
+/// evaluated individually. `LANES` should generally be chosen such that `ArrayWrap<LANES, F>` fills one or two
+/// SIMD registers of the target, such as 8 for `f32` with AVX2. Larger values may spill to memory.
+///
+/// ```rust
+/// use fast_polynomial::{poly_array, many_xs::poly_array_batch};
+///
+/// let xs: Vec<f32> = (0..100).map(|i| i as f32 / 100.0).collect();
+/// let c = [1.0, 0.3, 0.4, 1.6];
+///
+/// let mut ys = vec![0.0; xs.len()];
+/// poly_array_batch::<8, _, 4>(&xs, &c, &mut ys);
+///
+/// for (&x, &y) in xs.iter().zip(&ys) {
+///     assert_eq!(y, poly_array(x, &c));
+/// }
+/// ```
+///
+/// # Panics
+///
+/// Panics if `LANES` is zero, or if `out` is not the same length as `xs`.
+pub fn poly_array_batch<const LANES: usize, F: PolyNum, const N: usize>(
+    xs: &[F],
+    coeffs: &[F; N],
+    out: &mut [F],
+) {
+    assert!(LANES > 0, "lane count must be non-zero");
+    assert_eq!(
+        xs.len(),
+        out.len(),
+        "output must be the same length as the inputs"
+    );
+
+    let coeffs_wide = coeffs.map(ArrayWrap::<LANES, F>::splat);
+
+    let mut xs_chunks = xs.chunks_exact(LANES);
+    let mut out_chunks = out.chunks_exact_mut(LANES);
+
+    for (x, y) in (&mut xs_chunks).zip(&mut out_chunks) {
+        let x = ArrayWrap::from_ref(x.try_into().unwrap());
+        let y = ArrayWrap::from_mut(y.try_into().unwrap());
+
+        *y = poly_array(*x, &coeffs_wide);
+    }
+
+    for (&x, y) in xs_chunks
+        .remainder()
+        .iter()
+        .zip(out_chunks.into_remainder())
+    {
+        *y = poly_array(x, coeffs);
+    }
+}
+
+/// Evaluate a polynomial at the `count` equally-spaced points `start + i * step`, writing the results to `out`.
+///
+/// The first `coeffs.len()` points are evaluated directly with [`poly`], from which the forward differences
+/// of the polynomial are computed. As the differences of order equal to the degree are constant,
+/// each further point then requires only one addition per coefficient, with no multiplications.
+///
+/// Rounding error in the differences accumulates with every step, so for floats the error grows with
+/// both `count` and the degree. This is best suited to plotting and tabulation of low-degree polynomials,
+/// or to integer types, for which the results are exact. The points themselves are accumulated by repeatedly
+/// adding `step` to `start`.
+///
+/// Requires the `alloc` crate feature.
+///
+/// ```rust
+/// use fast_polynomial::many_xs::poly_grid;
+///
+/// // x^2 - 2x + 3 at x = -2, 1, 4, 7, 10
+/// let mut out = [0i64; 5];
+/// poly_grid(&[3, -2, 1], -2, 3, 5, &mut out);
+///
+/// assert_eq!(out, [11, 2, 11, 38, 83]);
+/// ```
+///
+/// # Panics
+///
+/// Panics if `out` is not `count` long.
+#[cfg(feature = "alloc")]
+pub fn poly_grid<F: PolyNumSigned>(coeffs: &[F], start: F, step: F, count: usize, out: &mut [F]) {
+    assert_eq!(out.len(), count, "output must be `count` long");
+
+    let n = coeffs.len();
+
+    if n == 0 {
+        out.fill(F::zero());
+        return;
+    }
+
+    let mut x = start;
+    let mut diffs = alloc::vec::Vec::with_capacity(n);
+
+    for _ in 0..n {
+        diffs.push(poly(x, coeffs));
+        x = x + step;
+    }
+
+    // diffs[j] becomes the j-th forward difference at the first point
+    for j in 1..n {
+        for i in (j..n).rev() {
+            diffs[i] = diffs[i] - diffs[i - 1];
+        }
+    }
+
+    for y in out.iter_mut() {
+        *y = diffs[0];
+
+        for j in 0..n - 1 {
+            diffs[j] = diffs[j] + diffs[j + 1];
+        }
+    }
+}
+
+impl<const N: usize, F> From<[F; N]> for ArrayWrap<N, F> {
+    #[inline(always)]
+    fn from(x: [F; N]) -> Self {
+        ArrayWrap(x)
+    }
+}
+
+impl<const N: usize, F> From<ArrayWrap<N, F>> for [F; N] {
+    #[inline(always)]
+    fn from(x: ArrayWrap<N, F>) -> Self {
+        x.0
+    }
+}
+
+// Tuples are foreign types, so the arithmetic traits required by `PolyNum` cannot be implemented
+// for them by this crate or any other. Instead, small tuples convert to and from `ArrayWrap`.
+macro_rules! impl_tuples {
+    ($($n:literal => ($($v:ident),*)),*) => {$(
+        impl<F> From<($(impl_tuples!(@ty $v F),)*)> for ArrayWrap<$n, F> {
+            #[inline(always)]
+            fn from(($($v,)*): ($(impl_tuples!(@ty $v F),)*)) -> Self {
+                ArrayWrap([$($v),*])
+            }
+        }
+
+        impl<F> From<ArrayWrap<$n, F>> for ($(impl_tuples!(@ty $v F),)*) {
+            #[inline(always)]
+            fn from(x: ArrayWrap<$n, F>) -> Self {
+                let [$($v),*] = x.0;
+                ($($v,)*)
+            }
+        }
+    )*};
+    (@ty $v:ident $F:ident) => { $F };
+}
+
+impl_tuples!(2 => (a, b), 3 => (a, b, c), 4 => (a, b, c, d));
+
+macro_rules! impl_binops {
+    ($($op:ident $method:ident),*) => {$(
+        impl<const N: usize, F: Copy + $op<Output = F>> $op for ArrayWrap<N, F> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $method(self, rhs: Self) -> Self {
+                ArrayWrap(core::array::from_fn(|i| self.0[i].$method(rhs.0[i])))
+            }
+        }
+
+        impl<const N: usize, F: Copy + $op<Output = F>> $op<F> for ArrayWrap<N, F> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $method(self, rhs: F) -> Self {
+                ArrayWrap(core::array::from_fn(|i| self.0[i].$method(rhs)))
+            }
+        }
+    )*};
+}
+
+impl_binops!(Add add, Sub sub, Mul mul, Div div, Rem rem);
+
+macro_rules! impl_assignops {
+    ($($op:ident $method:ident),*) => {$(
+        impl<const N: usize, F: Copy + $op> $op for ArrayWrap<N, F> {
+            #[inline(always)]
+            fn $method(&mut self, rhs: Self) {
+                for (a, b) in self.0.iter_mut().zip(rhs.0) {
+                    a.$method(b);
+                }
+            }
+        }
+
+        impl<const N: usize, F: Copy + $op> $op<F> for ArrayWrap<N, F> {
+            #[inline(always)]
+            fn $method(&mut self, rhs: F) {
+                for a in &mut self.0 {
+                    a.$method(rhs);
+                }
+            }
+        }
+    )*};
+}
+
+impl_assignops!(AddAssign add_assign, SubAssign sub_assign, MulAssign mul_assign, DivAssign div_assign, RemAssign rem_assign);
+
+impl<const N: usize, F: Copy + Neg<Output = F>> Neg for ArrayWrap<N, F> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self {
+        self.map(Neg::neg)
+    }
+}
+
+// Scalar on the left-hand side can't be implemented generically due to the orphan rules,
+// so implement them for each primitive type instead.
+macro_rules! impl_scalar_lhs {
+    ($($ty:ty),*) => {$(
+        impl_scalar_lhs!(@ops $ty; Add add, Sub sub, Mul mul, Div div, Rem rem);
+    )*};
+
+    (@ops $ty:ty; $($op:ident $method:ident),*) => {$(
+        impl<const N: usize> $op<ArrayWrap<N, $ty>> for $ty {
+            type Output = ArrayWrap<N, $ty>;
+
+            #[inline(always)]
+            fn $method(self, rhs: ArrayWrap<N, $ty>) -> ArrayWrap<N, $ty> {
+                ArrayWrap(core::array::from_fn(|i| self.$method(rhs.0[i])))
+            }
+        }
+    )*};
+}
+
+impl_scalar_lhs!(f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl<const N: usize, F: Copy + MulAdd<Output = F>> MulAdd for ArrayWrap<N, F> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        ArrayWrap(core::array::from_fn(|i| self.0[i].mul_add(a.0[i], b.0[i])))
+    }
+}
+
+/// Multiply each lane by a scalar, then add another vector.
+impl<const N: usize, F: Copy + MulAdd<Output = F>> MulAdd<F, Self> for ArrayWrap<N, F> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn mul_add(self, a: F, b: Self) -> Self {
+        ArrayWrap(core::array::from_fn(|i| self.0[i].mul_add(a, b.0[i])))
+    }
+}
+
+impl<const N: usize, F: Copy + Zero> Zero for ArrayWrap<N, F> {
+    #[inline(always)]
+    fn zero() -> Self {
+        ArrayWrap([F::zero(); N])
+    }
+
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.0.iter().all(Zero::is_zero)
+    }
+}
+
+impl<const N: usize, F: Copy + One> One for ArrayWrap<N, F> {
+    #[inline(always)]
+    fn one() -> Self {
+        ArrayWrap([F::one(); N])
+    }
+}
@@ -0,0 +1,99 @@
+//! Memoized evaluation of polynomials at repeated inputs.
+//!
+//! Requires the `std` crate feature.
+
+use std::collections::{HashMap, VecDeque};
+use std::vec::Vec;
+
+use crate::poly;
+
+/// A polynomial with `f64` coefficients that caches its results by input.
+///
+/// Inputs are keyed by their exact bit pattern, so `0.0` and `-0.0` are cached separately, as are NaNs
+/// with different payloads. Once `capacity` results are cached, the oldest is evicted for each new input,
+/// regardless of how often it was used.
+///
+/// Evaluating a polynomial with [`poly`] is usually only a few nanoseconds, which is comparable
+/// to the cost of hashing and looking up the input, so this is only a win for high-degree polynomials
+/// where evaluations genuinely repeat, such as in recursive algorithms that revisit the same points.
+/// Benchmark before using this in place of direct evaluation.
+///
+/// ```rust
+/// use fast_polynomial::{poly, memo::MemoPoly};
+///
+/// let coeffs: Vec<f64> = (0..64).map(|i| 1.0 / (i + 1) as f64).collect();
+/// let mut p = MemoPoly::new(coeffs.clone(), 1024);
+///
+/// assert_eq!(p.eval(0.5), poly(0.5, &coeffs));
+/// assert_eq!(p.eval(0.5), poly(0.5, &coeffs)); // cached
+/// assert_eq!(p.len(), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MemoPoly {
+    coeffs: Vec<f64>,
+    cache: HashMap<u64, f64>,
+    order: VecDeque<u64>,
+    capacity: usize,
+}
+
+impl MemoPoly {
+    /// Create a new memoized polynomial, caching at most `capacity` results.
+    ///
+    /// A capacity of zero disables caching entirely.
+    pub fn new(coeffs: Vec<f64>, capacity: usize) -> Self {
+        MemoPoly {
+            coeffs,
+            cache: HashMap::new(),
+            order: VecDeque::new(),
+            capacity,
+        }
+    }
+
+    /// Evaluate the polynomial at `x`, returning the cached result if `x` has been evaluated before.
+    pub fn eval(&mut self, x: f64) -> f64 {
+        let key = x.to_bits();
+
+        if let Some(&y) = self.cache.get(&key) {
+            return y;
+        }
+
+        let y = poly(x, &self.coeffs);
+
+        if self.capacity > 0 {
+            if self.order.len() == self.capacity {
+                if let Some(oldest) = self.order.pop_front() {
+                    self.cache.remove(&oldest);
+                }
+            }
+
+            self.order.push_back(key);
+            self.cache.insert(key, y);
+        }
+
+        y
+    }
+
+    /// The coefficients of the polynomial.
+    #[inline]
+    pub fn coeffs(&self) -> &[f64] {
+        &self.coeffs
+    }
+
+    /// The number of cached results.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// Returns `true` if there are no cached results.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.cache.is_empty()
+    }
+
+    /// Remove all cached results.
+    pub fn clear(&mut self) {
+        self.cache.clear();
+        self.order.clear();
+    }
+}
@@ -16,36 +16,42 @@
 
 use crate::{fma, PolyNum};
 
-#[inline(always)]
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
 pub fn poly_1<F: PolyNum>(x: F, c0: F, c1: F) -> F {
     fma(x, c1, c0)
 }
 
-#[inline(always)]
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
 pub fn poly_2<F: PolyNum>(x: F, x2: F, c0: F, c1: F, c2: F) -> F {
     fma(x2, c2, fma(x, c1, c0))
 }
 
-#[inline(always)]
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
 pub fn poly_3<F: PolyNum>(x: F, x2: F, c0: F, c1: F, c2: F, c3: F) -> F {
     // x^2 * (x * c3 + c2) + (x*c1 + c0)
     fma(x2, fma(x, c3, c2), fma(x, c1, c0))
 }
 
-#[inline(always)]
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
 pub fn poly_4<F: PolyNum>(x: F, x2: F, x4: F, c0: F, c1: F, c2: F, c3: F, c4: F) -> F {
     // x^4 * c4 + (x^2 * (x * c3 + c2) + (x*c1 + c0))
     fma(x4, c4, fma(x2, fma(x, c3, c2), fma(x, c1, c0)))
 }
 
-#[inline(always)]
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
 pub fn poly_5<F: PolyNum>(x: F, x2: F, x4: F, c0: F, c1: F, c2: F, c3: F, c4: F, c5: F) -> F {
     // x^4 * (x * c5 + c4) + (x^2 * (x * c3 + c2) + (x*c1 + c0))
     fma(x4, fma(x, c5, c4), fma(x2, fma(x, c3, c2), fma(x, c1, c0)))
 }
 
 #[rustfmt::skip]
-#[inline(always)]
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
 pub fn poly_6<F: PolyNum>(x: F, x2: F, x4: F, c0: F, c1: F, c2: F, c3: F, c4: F, c5: F, c6: F) -> F {
     // x^4 * (x^2 * c6 + (x * c5 + c4)) + (x^2 * (x * c3 + c2) + (x * c1 + c0))
     fma(x4,
@@ -55,7 +61,8 @@ pub fn poly_6<F: PolyNum>(x: F, x2: F, x4: F, c0: F, c1: F, c2: F, c3: F, c4: F,
 }
 
 #[rustfmt::skip]
-#[inline(always)]
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
 pub fn poly_7<F: PolyNum>(x: F, x2: F, x4: F, c0: F, c1: F, c2: F, c3: F, c4: F, c5: F, c6: F, c7: F) -> F {
     fma(x4,
         fma(x2, fma(x, c7, c6), fma(x, c5, c4)),
@@ -64,7 +71,8 @@ pub fn poly_7<F: PolyNum>(x: F, x2: F, x4: F, c0: F, c1: F, c2: F, c3: F, c4: F,
 }
 
 #[rustfmt::skip]
-#[inline(always)]
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
 pub fn poly_8<F: PolyNum>(
     x: F, x2: F, x4: F, x8: F,
     c0: F, c1: F, c2: F, c3: F, c4: F, c5: F, c6: F, c7: F, c8: F
@@ -76,7 +84,8 @@ pub fn poly_8<F: PolyNum>(
 }
 
 #[rustfmt::skip]
-#[inline(always)]
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
 pub fn poly_9<F: PolyNum>(
     x: F, x2: F, x4: F, x8: F,
     c0: F, c1: F, c2: F, c3: F, c4: F, c5: F, c6: F, c7: F, c8: F, c9: F
@@ -88,7 +97,8 @@ pub fn poly_9<F: PolyNum>(
 }
 
 #[rustfmt::skip]
-#[inline(always)]
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
 pub fn poly_10<F: PolyNum>(
     x: F, x2: F, x4: F, x8: F,
     c0: F, c1: F, c2: F, c3: F, c4: F, c5: F, c6: F, c7: F, c8: F, c9: F, c10: F,
@@ -100,7 +110,8 @@ pub fn poly_10<F: PolyNum>(
 }
 
 #[rustfmt::skip]
-#[inline(always)]
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
 pub fn poly_11<F: PolyNum>(
     x: F, x2: F, x4: F, x8: F,
     c0: F, c1: F, c2: F, c3: F, c4: F, c5: F, c6: F, c7: F, c8: F, c9: F, c10: F, c11: F
@@ -115,7 +126,8 @@ pub fn poly_11<F: PolyNum>(
 }
 
 #[rustfmt::skip]
-#[inline(always)]
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
 pub fn poly_12<F: PolyNum>(
     x: F, x2: F, x4: F, x8: F,
     c0: F, c1: F, c2: F, c3: F, c4: F, c5: F, c6: F, c7: F, c8: F, c9: F, c10: F, c11: F, c12: F,
@@ -133,7 +145,8 @@ pub fn poly_12<F: PolyNum>(
 }
 
 #[rustfmt::skip]
-#[inline(always)]
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
 pub fn poly_13<F: PolyNum>(
     x: F, x2: F, x4: F, x8: F,
     c0: F, c1: F, c2: F, c3: F, c4: F, c5: F, c6: F, c7: F, c8: F, c9: F, c10: F, c11: F, c12: F, c13: F,
@@ -151,7 +164,8 @@ pub fn poly_13<F: PolyNum>(
 }
 
 #[rustfmt::skip]
-#[inline(always)]
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
 pub fn poly_14<F: PolyNum>(
     x: F, x2: F, x4: F, x8: F,
     c0: F, c1: F, c2: F, c3: F, c4: F, c5: F, c6: F, c7: F, c8: F, c9: F, c10: F, c11: F, c12: F, c13: F, c14: F
@@ -170,7 +184,8 @@ pub fn poly_14<F: PolyNum>(
 }
 
 #[rustfmt::skip]
-#[inline(always)]
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
 pub fn poly_15<F: PolyNum>(
     x: F, x2: F, x4: F, x8: F,
     c0: F, c1: F, c2: F, c3: F, c4: F, c5: F, c6: F, c7: F, c8: F, c9: F, c10: F, c11: F, c12: F, c13: F, c14: F, c15: F
@@ -189,7 +204,8 @@ pub fn poly_15<F: PolyNum>(
 }
 
 #[rustfmt::skip]
-#[inline(always)]
+#[cfg_attr(not(feature = "no-force-inline"), inline(always))]
+#[cfg_attr(feature = "no-force-inline", inline)]
 pub fn poly_31<F: PolyNum>(
     x: F, x2: F, x4: F, x8: F, x16: F,
     c00: F, c01: F, c02: F, c03: F, c04: F, c05: F, c06: F, c07: F, c08: F, c09: F, c10: F, c11: F, c12: F, c13: F, c14: F, c15: F,
@@ -0,0 +1,285 @@
+//! Evaluation of fixed polynomials with fewer multiplications, by preprocessing their coefficients.
+//!
+//! Requires the `alloc` crate feature, and either the `std` or `libm` crate features.
+
+use alloc::vec::Vec;
+
+use crate::algebra::{degree, taylor_shift};
+
+/// A polynomial with `f64` coefficients, preprocessed to be evaluated with fewer multiplications
+/// than Horner's method.
+///
+/// This is Eve's adaptation of the coefficients, as described by Knuth in _The Art of Computer Programming_,
+/// Vol. 2, §4.6.4. A polynomial of degree `n >= 3` is rewritten in terms of `y = x + t` and `z = y^2` as
+///
+/// ```text
+/// p(x) = ((r(y) (z - α_k) + γ_k) ... ) (z - α_1) + γ_1
+/// ```
+///
+/// where `r(y)` is linear for odd `n` and `r(y) = e_1 z + o y + e_0` for even `n`, which takes `⌊n/2⌋ + 2`
+/// multiplications and `n + 1` additions, compared to `n` of each for Horner's method.
+///
+/// The `α_j` are the roots of the odd part of the shifted polynomial, which are all real when all roots
+/// of the shifted polynomial lie in the same half of the complex plane. The shift `t` is chosen during
+/// preprocessing to make this so, preferring the smallest shift for which the results agree with Horner's method
+/// for `|x| <= 1`, the usual domain of approximations after range reduction. Even so, the preprocessed form
+/// is less accurate, so this is best suited to low and moderate degree polynomials evaluated many times
+/// at scalar inputs, where the multiplications are the bottleneck.
+///
+/// Unlike the rest of this crate, evaluation is a single dependency chain and never uses fused multiply-add.
+///
+/// # Accuracy
+///
+/// A shift is only accepted if, at `4(n + 1) + 1` equally-spaced points spanning `[-1, 1]`, the preprocessed
+/// form differs from Horner's method by at most [`TOLERANCE`](Self::TOLERANCE) times `Σ |c_k| |x|^k`,
+/// the sum of the magnitudes of the terms. Each root `α_j` must also satisfy the odd part to within the
+/// same relative tolerance. If no shift passes both checks, [`new`](Self::new) returns `None`.
+/// Outside of `[-1, 1]`, the error is not checked.
+///
+/// ```rust
+/// use fast_polynomial::{poly, preprocessed::PreprocessedPoly};
+///
+/// let coeffs = [1.0, -2.0, 0.5, 3.0, -1.0, 0.25, 2.0];
+/// let p = PreprocessedPoly::new(&coeffs).unwrap();
+///
+/// assert_eq!(p.multiplications(), 5); // versus 6 for Horner's method
+///
+/// for x in [-1.5, -0.25, 0.0, 0.5, 2.0] {
+///     assert!((p.eval(x) - poly(x, &coeffs)).abs() < 1e-9);
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct PreprocessedPoly {
+    shift: f64,
+    /// `(α_j, γ_j)`, outermost first
+    factors: Vec<(f64, f64)>,
+    /// `(e_0, e_1, o)` of the innermost `r(y)`
+    rem: (f64, f64, f64),
+    even: bool,
+}
+
+impl PreprocessedPoly {
+    /// Tolerance used to accept the preprocessed form of a polynomial and its roots,
+    /// relative to the sum of the magnitudes of the terms. See [Accuracy](Self#accuracy).
+    pub const TOLERANCE: f64 = 1e-9;
+
+    /// Preprocess the given coefficients, in order of increasing degree.
+    ///
+    /// Trailing zero coefficients are ignored. Returns `None` if any coefficient is not finite,
+    /// or if no shift could be found for which the preprocessed form is accurate to within
+    /// [`TOLERANCE`](Self::TOLERANCE), as described under [Accuracy](Self#accuracy),
+    /// which becomes more likely for higher degrees.
+    pub fn new(coeffs: &[f64]) -> Option<Self> {
+        if coeffs.iter().any(|c| !c.is_finite()) {
+            return None;
+        }
+
+        let Some(n) = degree(coeffs) else {
+            return Some(Self::split(0.0, &[0.0]));
+        };
+
+        let coeffs = &coeffs[..=n];
+
+        if n < 3 {
+            return Some(Self::split(0.0, coeffs));
+        }
+
+        // Cauchy's bound, all roots lie within |x| < b
+        let b = 1.0
+            + coeffs[..n]
+                .iter()
+                .map(|c| (c / coeffs[n]).abs())
+                .fold(0.0, f64::max);
+
+        // All roots of p(y - t) lie in the left half-plane for t = -b, and in the right for t = b,
+        // but smaller shifts are more accurate and often also suffice.
+        let shifts = (0..=16).map(|i| b * i as f64 / 16.0);
+
+        shifts.flat_map(|t| [-t, t]).find_map(|t| {
+            let mut v = coeffs.to_vec();
+            taylor_shift(&mut v, -t);
+
+            Self::preprocess(t, &v).filter(|p| p.accurate(coeffs))
+        })
+    }
+
+    /// Split the coefficients of the shifted polynomial `v(y)` into `r(y)`, assuming it has degree `< 3`.
+    fn split(shift: f64, v: &[f64]) -> Self {
+        let c = |k: usize| v.get(k).copied().unwrap_or(0.0);
+
+        PreprocessedPoly {
+            shift,
+            factors: Vec::new(),
+            rem: (c(0), c(2), c(1)),
+            even: v.len() == 3,
+        }
+    }
+
+    /// Factor the shifted polynomial `v(y) = E(z) + y O(z)` by the real roots of `O(z)`.
+    fn preprocess(shift: f64, v: &[f64]) -> Option<Self> {
+        let mut e: Vec<f64> = v.iter().copied().step_by(2).collect();
+        let mut o: Vec<f64> = v.iter().copied().skip(1).step_by(2).collect();
+
+        let roots = real_roots(&o)?;
+        let mut factors = Vec::with_capacity(roots.len());
+
+        for alpha in roots {
+            let gamma = deflate(&mut e, alpha);
+            deflate(&mut o, alpha);
+
+            factors.push((alpha, gamma));
+        }
+
+        Some(PreprocessedPoly {
+            shift,
+            factors,
+            rem: (e[0], e.get(1).copied().unwrap_or(0.0), o[0]),
+            even: e.len() == 2,
+        })
+    }
+
+    /// Check that the preprocessed form agrees with Horner's method at points spanning `[-1, 1]`.
+    fn accurate(&self, coeffs: &[f64]) -> bool {
+        let n = 4 * coeffs.len();
+
+        (0..=n).all(|i| {
+            let x = 2.0 * i as f64 / n as f64 - 1.0;
+
+            let (mut y, mut mag) = (0.0, 0.0);
+
+            for &c in coeffs.iter().rev() {
+                y = y * x + c;
+                mag = mag * x.abs() + c.abs();
+            }
+
+            (self.eval(x) - y).abs() <= Self::TOLERANCE * mag
+        })
+    }
+
+    /// Evaluate the polynomial at `x`.
+    #[inline]
+    pub fn eval(&self, x: f64) -> f64 {
+        let (e0, e1, o) = self.rem;
+
+        let y = x + self.shift;
+        let z = y * y;
+
+        let mut sum = o * y + e0;
+
+        if self.even {
+            sum += e1 * z;
+        }
+
+        for &(alpha, gamma) in self.factors.iter().rev() {
+            sum = sum * (z - alpha) + gamma;
+        }
+
+        sum
+    }
+
+    /// Returns the number of multiplications performed by [`eval`](Self::eval).
+    #[inline]
+    pub fn multiplications(&self) -> usize {
+        2 + self.even as usize + self.factors.len()
+    }
+}
+
+/// Divide the polynomial in-place by `(z - alpha)`, returning the remainder.
+fn deflate(p: &mut Vec<f64>, alpha: f64) -> f64 {
+    let mut carry = 0.0;
+
+    for c in p.iter_mut().rev() {
+        let next = *c + alpha * carry;
+        *c = carry;
+        carry = next;
+    }
+
+    p.pop();
+
+    carry
+}
+
+/// Find all roots of the polynomial, largest first, or `None` if they are not all real.
+///
+/// Newton's method started to the right of every root converges monotonically to the largest root
+/// of a polynomial with only real roots, which is then deflated and polished on the original polynomial.
+fn real_roots(p: &[f64]) -> Option<Vec<f64>> {
+    let mut q = p.to_vec();
+    let mut roots = Vec::with_capacity(p.len().saturating_sub(1));
+
+    while q.len() > 1 {
+        let lead = q[q.len() - 1];
+        let bound = 1.0 + q.iter().map(|c| (c / lead).abs()).fold(0.0, f64::max);
+
+        let root = polish(p, newton(&q, bound)?);
+
+        let (f, _, mag) = eval_deriv(p, root);
+
+        if f.is_nan() || f.abs() > PreprocessedPoly::TOLERANCE * mag {
+            return None;
+        }
+
+        deflate(&mut q, root);
+        roots.push(root);
+    }
+
+    Some(roots)
+}
+
+/// Newton's method from the right, stopping once the iterates no longer decrease.
+fn newton(p: &[f64], mut z: f64) -> Option<f64> {
+    for _ in 0..1000 {
+        let (f, df, _) = eval_deriv(p, z);
+
+        if f == 0.0 {
+            return Some(z);
+        }
+
+        let next = z - f / df;
+
+        if next.is_nan() || next >= z {
+            return next.is_finite().then_some(z);
+        }
+
+        z = next;
+    }
+
+    None
+}
+
+/// A few unguarded Newton steps on the undeflated polynomial, keeping the best iterate.
+fn polish(p: &[f64], mut z: f64) -> f64 {
+    let mut best = (eval_deriv(p, z).0.abs(), z);
+
+    for _ in 0..4 {
+        let (f, df, _) = eval_deriv(p, z);
+        let next = z - f / df;
+
+        if !next.is_finite() {
+            break;
+        }
+
+        z = next;
+
+        let r = eval_deriv(p, z).0.abs();
+
+        if r < best.0 {
+            best = (r, z);
+        }
+    }
+
+    best.1
+}
+
+/// Evaluate the polynomial, its derivative and the sum of the magnitudes of its terms at `z`.
+fn eval_deriv(p: &[f64], z: f64) -> (f64, f64, f64) {
+    let (mut f, mut df, mut mag) = (0.0, 0.0, 0.0);
+
+    for &c in p.iter().rev() {
+        df = df * z + f;
+        f = f * z + c;
+        mag = mag * z.abs() + c.abs();
+    }
+
+    (f, df, mag)
+}
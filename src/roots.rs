@@ -0,0 +1,266 @@
+//! Analytic solutions for the real roots of polynomials up to degree 4.
+//!
+//! Unlike the rest of this crate, coefficients here are given in the conventional order for
+//! each equation, such as `a x² + b x + c` for [`roots_quadratic`], highest degree first.
+//!
+//! Roots are always returned in ascending order. Repeated roots are returned once per
+//! multiplicity when the discriminant is exactly zero, but due to rounding error may
+//! otherwise appear as a pair of very close roots, or not at all.
+//!
+//! Requires either the `std` or `libm` crate features.
+
+use core::ops::Deref;
+use num_traits::Float;
+
+/// The real roots of a polynomial up to degree 4, in ascending order.
+///
+/// Dereferences to a slice of the roots found.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Roots<F> {
+    roots: [F; 4],
+    len: usize,
+}
+
+impl<F: Float> Roots<F> {
+    #[inline]
+    fn new() -> Self {
+        Roots {
+            roots: [F::zero(); 4],
+            len: 0,
+        }
+    }
+
+    #[inline]
+    fn push(&mut self, x: F) {
+        self.roots[self.len] = x;
+        self.len += 1;
+    }
+
+    fn sorted(mut self) -> Self {
+        // insertion sort, as there are at most 4 roots
+        for i in 1..self.len {
+            let mut j = i;
+            while j > 0 && self.roots[j - 1] > self.roots[j] {
+                self.roots.swap(j - 1, j);
+                j -= 1;
+            }
+        }
+
+        self
+    }
+
+    /// Apply one step of Newton's method to each root, keeping the result only if it improves the residual.
+    fn polished(mut self, coeffs: &[F]) -> Self {
+        for x in &mut self.roots[..self.len] {
+            let (mut p, mut dp) = (F::zero(), F::zero());
+            for &c in coeffs {
+                dp = dp.mul_add(*x, p);
+                p = p.mul_add(*x, c);
+            }
+
+            if dp != F::zero() {
+                let nx = *x - p / dp;
+
+                let mut np = F::zero();
+                for &c in coeffs {
+                    np = np.mul_add(nx, c);
+                }
+
+                if np.abs() < p.abs() {
+                    *x = nx;
+                }
+            }
+        }
+
+        self
+    }
+}
+
+impl<F> Deref for Roots<F> {
+    type Target = [F];
+
+    #[inline]
+    fn deref(&self) -> &[F] {
+        &self.roots[..self.len]
+    }
+}
+
+#[inline(always)]
+fn lit<F: Float>(x: f64) -> F {
+    F::from(x).unwrap()
+}
+
+/// Find the real root of the linear equation `a x + b = 0`.
+///
+/// If `a` is zero, there are no roots (or infinitely many, if `b` is also zero), and none are returned.
+pub fn roots_linear<F: Float>(a: F, b: F) -> Roots<F> {
+    let mut roots = Roots::new();
+
+    if a != F::zero() {
+        roots.push(-b / a);
+    }
+
+    roots
+}
+
+/// Find the real roots of the quadratic equation `a x² + b x + c = 0`.
+///
+/// This uses the numerically stable form of the quadratic formula, which avoids catastrophic cancellation
+/// when `b² ≫ 4ac` by computing `q = -(b + sign(b) √(b² - 4ac)) / 2`, and the roots as `q / a` and `c / q`.
+///
+/// If `a` is zero, this falls back to [`roots_linear`].
+pub fn roots_quadratic<F: Float>(a: F, b: F, c: F) -> Roots<F> {
+    if a == F::zero() {
+        return roots_linear(b, c);
+    }
+
+    let mut roots = Roots::new();
+
+    let disc = b.mul_add(b, -(a * c) * lit(4.0));
+
+    if disc < F::zero() {
+        return roots;
+    }
+
+    if disc == F::zero() {
+        let x = -b / (a + a);
+        roots.push(x);
+        roots.push(x);
+        return roots;
+    }
+
+    let q = (b + disc.sqrt().copysign(b)) * lit(-0.5);
+
+    roots.push(q / a);
+    // q can only be zero if both b and c are zero, in which case the discriminant is zero as well
+    roots.push(c / q);
+
+    roots.sorted()
+}
+
+/// Find the real roots of the cubic equation `a x³ + b x² + c x + d = 0`.
+///
+/// The cubic is reduced to the depressed form `t³ + pt + q = 0`, then solved with Cardano's formula
+/// when there is one real root, or the trigonometric method when there are three. Each root is then
+/// refined with a single step of Newton's method.
+///
+/// If `a` is zero, this falls back to [`roots_quadratic`].
+pub fn roots_cubic<F: Float>(a: F, b: F, c: F, d: F) -> Roots<F> {
+    if a == F::zero() {
+        return roots_quadratic(b, c, d);
+    }
+
+    let mut roots = Roots::new();
+
+    // normalize to x³ + A x² + B x + C
+    let (ca, cb, cc) = (b / a, c / a, d / a);
+
+    let third = lit::<F>(1.0 / 3.0);
+    let shift = ca * third;
+
+    // substitute x = t - A/3
+    let p = cb - ca * shift;
+    let q = shift * (lit::<F>(2.0 / 9.0) * ca * ca - cb) + cc;
+
+    let half_q = q * lit(0.5);
+    let third_p = p * third;
+
+    let disc = half_q * half_q + third_p * third_p * third_p;
+
+    if disc > F::zero() {
+        // one real root, using the form that avoids cancellation between the two cube roots
+        let u = (-half_q - disc.sqrt().copysign(half_q)).cbrt();
+        let t = if u == F::zero() { u } else { u - third_p / u };
+
+        roots.push(t - shift);
+    } else if disc == F::zero() {
+        if q == F::zero() {
+            // triple root
+            for _ in 0..3 {
+                roots.push(-shift);
+            }
+        } else {
+            // one single and one double root
+            let t1 = lit::<F>(3.0) * q / p;
+            let t2 = lit::<F>(-1.5) * q / p;
+
+            roots.push(t1 - shift);
+            roots.push(t2 - shift);
+            roots.push(t2 - shift);
+        }
+    } else {
+        // three distinct real roots, p must be negative here
+        let r = lit::<F>(2.0) * (-third_p).sqrt();
+        let phi = (lit::<F>(3.0) * q / (p * r))
+            .max(-F::one())
+            .min(F::one())
+            .acos()
+            * third;
+
+        let tau = lit::<F>(core::f64::consts::TAU / 3.0);
+
+        for k in 0..3 {
+            let t = r * (phi - tau * F::from(k).unwrap()).cos();
+            roots.push(t - shift);
+        }
+    }
+
+    roots.polished(&[a, b, c, d]).sorted()
+}
+
+/// Find the real roots of the quartic equation `a x⁴ + b x³ + c x² + d x + e = 0`.
+///
+/// The quartic is reduced to the depressed form `y⁴ + p y² + q y + r = 0`, then factored into two
+/// quadratics using a root of Ferrari's resolvent cubic. Each root is then refined with a single step
+/// of Newton's method.
+///
+/// If `a` is zero, this falls back to [`roots_cubic`].
+pub fn roots_quartic<F: Float>(a: F, b: F, c: F, d: F, e: F) -> Roots<F> {
+    if a == F::zero() {
+        return roots_cubic(b, c, d, e);
+    }
+
+    let mut roots = Roots::new();
+
+    // normalize to x⁴ + A x³ + B x² + C x + D
+    let (ca, cb, cc, cd) = (b / a, c / a, d / a, e / a);
+
+    let shift = ca * lit(0.25);
+    let s2 = shift * shift;
+
+    // substitute x = y - A/4
+    let p = cb - lit::<F>(6.0) * s2;
+    let q = cc - lit::<F>(2.0) * cb * shift + lit::<F>(8.0) * s2 * shift;
+    let r = cd - cc * shift + cb * s2 - lit::<F>(3.0) * s2 * s2;
+
+    let mut push_quadratic = |b: F, c: F| {
+        for y in roots_quadratic(F::one(), b, c).iter() {
+            roots.push(*y - shift);
+        }
+    };
+
+    if q == F::zero() {
+        // biquadratic, solve for z = y²
+        for z in roots_quadratic(F::one(), p, r).iter() {
+            if *z >= F::zero() {
+                push_quadratic(F::zero(), -*z);
+            }
+        }
+    } else {
+        // the resolvent cubic m³ + p m² + (p²/4 - r) m - q²/8 = 0 always has a positive root when q != 0
+        let resolvent = roots_cubic(F::one(), p, p * p * lit(0.25) - r, -(q * q) * lit(0.125));
+
+        let m = resolvent[resolvent.len() - 1];
+
+        if m > F::zero() {
+            let s = (m + m).sqrt();
+            let k = q / (lit::<F>(4.0) * m) * s;
+            let h = p * lit(0.5) + m;
+
+            push_quadratic(-s, h + k);
+            push_quadratic(s, h - k);
+        }
+    }
+
+    roots.polished(&[a, b, c, d, e]).sorted()
+}
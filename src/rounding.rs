@@ -0,0 +1,136 @@
+//! Evaluation with directed hardware rounding, for computing rigorous enclosures.
+//!
+//! The functions here switch the floating-point environment to round toward positive or negative
+//! infinity with the C library's `fesetround`, evaluate the polynomial, then restore the previous
+//! rounding mode. Evaluating the same polynomial once with each gives an interval guaranteed to contain
+//! the exact value of the polynomial for the given (exactly-representable) coefficients and `x`:
+//!
+//! ```rust
+//! use fast_polynomial::rounding::{poly_array_round_down, poly_array_round_up};
+//!
+//! let c = [0.1, -0.7, 1.3, 0.3];
+//! let (lo, hi) = (poly_array_round_down(-0.3, &c), poly_array_round_up(-0.3, &c));
+//!
+//! assert!(lo <= hi);
+//! ```
+//!
+//! Unlike the rest of this crate, Horner's method is always used rather than Estrin's scheme,
+//! as the rounding of the powers of `x` required by Estrin's scheme can bound the result in the wrong
+//! direction when multiplied by a negative coefficient. For the same reason, negative `x` is evaluated
+//! as `p(-y)` with `y = -x` and alternating coefficients negated, keeping every multiplication monotonic.
+//!
+//! # Caveats
+//!
+//! Rust and LLVM assume the default rounding mode, so the evaluation is kept in a separate, non-inlined
+//! function with its inputs and outputs passed through [`black_box`](core::hint::black_box) to prevent
+//! the compiler from constant-folding or moving it outside of the rounding mode change. This is
+//! best-effort rather than a language guarantee.
+//!
+//! Requires the `rounding` crate feature, which implies `std`.
+
+use core::ffi::c_int;
+use core::hint::black_box;
+
+use crate::fma;
+
+#[cfg(target_env = "msvc")]
+mod consts {
+    use super::c_int;
+
+    pub const FE_DOWNWARD: c_int = 0x0100;
+    pub const FE_UPWARD: c_int = 0x0200;
+}
+
+#[cfg(all(
+    not(target_env = "msvc"),
+    any(target_arch = "x86", target_arch = "x86_64")
+))]
+mod consts {
+    use super::c_int;
+
+    pub const FE_DOWNWARD: c_int = 0x400;
+    pub const FE_UPWARD: c_int = 0x800;
+}
+
+#[cfg(all(
+    not(target_env = "msvc"),
+    any(target_arch = "aarch64", target_arch = "arm")
+))]
+mod consts {
+    use super::c_int;
+
+    pub const FE_UPWARD: c_int = 0x400000;
+    pub const FE_DOWNWARD: c_int = 0x800000;
+}
+
+#[cfg(not(any(
+    target_env = "msvc",
+    target_arch = "x86",
+    target_arch = "x86_64",
+    target_arch = "aarch64",
+    target_arch = "arm"
+)))]
+compile_error!("the `rounding` feature is not supported on this target");
+
+extern "C" {
+    fn fegetround() -> c_int;
+    fn fesetround(round: c_int) -> c_int;
+}
+
+/// Evaluate a polynomial with every operation rounded toward positive infinity,
+/// giving an upper bound on the exact value.
+///
+/// See the [module-level documentation](self) for details.
+#[inline]
+pub fn poly_array_round_up<const N: usize>(x: f64, coeffs: &[f64; N]) -> f64 {
+    poly_rounded(consts::FE_UPWARD, x, coeffs)
+}
+
+/// Evaluate a polynomial with every operation rounded toward negative infinity,
+/// giving a lower bound on the exact value.
+///
+/// See the [module-level documentation](self) for details.
+#[inline]
+pub fn poly_array_round_down<const N: usize>(x: f64, coeffs: &[f64; N]) -> f64 {
+    poly_rounded(consts::FE_DOWNWARD, x, coeffs)
+}
+
+#[inline(never)]
+fn poly_rounded(mode: c_int, x: f64, coeffs: &[f64]) -> f64 {
+    unsafe {
+        let prev = fegetround();
+
+        if fesetround(mode) != 0 {
+            panic!("failed to set floating-point rounding mode");
+        }
+
+        let res = black_box(horner_monotonic(black_box(x), black_box(coeffs)));
+
+        fesetround(prev);
+
+        res
+    }
+}
+
+#[inline(never)]
+fn horner_monotonic(x: f64, coeffs: &[f64]) -> f64 {
+    let mut sum = 0.0;
+
+    if x < 0.0 {
+        // p(x) = p(-y) = Σ (-1)^k c_k y^k, where y >= 0 and negation is exact
+        //
+        // LLVM would otherwise rewrite `sum * -x` as `-(sum * x)`, which is only equivalent when rounding
+        // to nearest, and rounds in the wrong direction here if the multiply-add is not fused
+        let y = black_box(-x);
+
+        for (k, &c) in coeffs.iter().enumerate().rev() {
+            sum = fma(sum, y, if k & 1 != 0 { -c } else { c });
+        }
+    } else {
+        for &c in coeffs.iter().rev() {
+            sum = fma(sum, x, c);
+        }
+    }
+
+    sum
+}
@@ -0,0 +1,157 @@
+//! Evaluation of polynomials too large to hold in memory at once, or of unknown length.
+
+use num_traits::One;
+
+use crate::{fma, poly, powi_nonzero, PolyNum};
+
+/// Incrementally evaluates a polynomial from chunks of its coefficients.
+///
+/// The full coefficient slice, in the usual order of increasing degree, is split into contiguous
+/// chunks which are then pushed starting from the _last_ chunk, i.e. the highest-degree coefficients
+/// first. Each chunk is evaluated with [`poly`], then combined with the previous result by Horner's method
+/// over chunks, `sum = sum * x^len + poly(x, chunk)`.
+///
+/// Only the running sum and the power of `x` for the most recent chunk length are stored,
+/// so arbitrarily large polynomials can be evaluated with bounded memory. Using the same
+/// chunk length throughout avoids recomputing the power of `x`.
+///
+/// ```rust
+/// use fast_polynomial::{poly, streaming::PolyAccumulator};
+///
+/// let coeffs: Vec<f64> = (0..100).map(|i| 1.0 / (i + 1) as f64).collect();
+/// let x = 0.9;
+///
+/// let mut acc = PolyAccumulator::new(x);
+/// for chunk in coeffs.rchunks(16) {
+///     acc.push(chunk);
+/// }
+///
+/// assert!((acc.finalize() - poly(x, &coeffs)).abs() < 1e-12);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct PolyAccumulator<F> {
+    x: F,
+    sum: F,
+    xn: F,
+    n: usize,
+    started: bool,
+}
+
+impl<F: PolyNum> PolyAccumulator<F> {
+    /// Begin evaluating a polynomial at `x`.
+    #[inline]
+    pub fn new(x: F) -> Self {
+        PolyAccumulator {
+            x,
+            sum: F::zero(),
+            xn: x,
+            n: 1,
+            started: false,
+        }
+    }
+
+    /// Push the next chunk of coefficients, which must directly precede the previous chunk
+    /// in order of degree. Empty chunks are ignored.
+    pub fn push(&mut self, chunk: &[F]) {
+        let len = chunk.len();
+
+        if len == 0 {
+            return;
+        }
+
+        let p = poly(self.x, chunk);
+
+        if !self.started {
+            // avoids multiplying zero by a potentially infinite power of x
+            self.sum = p;
+            self.started = true;
+            return;
+        }
+
+        if len != self.n {
+            self.xn = powi_nonzero(self.x, len);
+            self.n = len;
+        }
+
+        self.sum = fma(self.sum, self.xn, p);
+    }
+
+    /// Returns the value of the polynomial of all chunks pushed so far, or zero if none were pushed.
+    #[inline]
+    pub fn finalize(self) -> F {
+        self.sum
+    }
+}
+
+/// Incrementally evaluates a power series one term at a time, in order of increasing degree.
+///
+/// Unlike [`PolyAccumulator`], which requires the highest-degree coefficients first, this maintains the
+/// current power of `x` so that each term can be added as soon as it is known, with the running sum
+/// returned after each term. This allows a series to be truncated adaptively, such as once the
+/// terms no longer change the sum.
+///
+/// Each term costs two multiplications rather than the single multiply-add of Horner's method,
+/// and the rounding error of the power of `x` grows linearly with the degree.
+///
+/// ```rust
+/// use fast_polynomial::streaming::SeriesEval;
+///
+/// // e^x = Σ x^k / k!
+/// let x = 0.5f64;
+/// let mut series = SeriesEval::new(x);
+/// let mut coeff = 1.0;
+/// let mut k = 0;
+///
+/// loop {
+///     let prev = series.sum();
+///     if series.add_term(coeff) == prev {
+///         break;
+///     }
+///
+///     k += 1;
+///     coeff /= k as f64;
+/// }
+///
+/// assert!((series.sum() - x.exp()).abs() < 1e-15);
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct SeriesEval<F> {
+    x: F,
+    sum: F,
+    xk: F,
+    terms: usize,
+}
+
+impl<F: PolyNum + One> SeriesEval<F> {
+    /// Begin evaluating a series at `x`.
+    #[inline]
+    pub fn new(x: F) -> Self {
+        SeriesEval {
+            x,
+            sum: F::zero(),
+            xk: F::one(),
+            terms: 0,
+        }
+    }
+
+    /// Add the coefficient of the next power of `x`, returning the updated sum.
+    #[inline]
+    pub fn add_term(&mut self, coeff: F) -> F {
+        self.sum = fma(coeff, self.xk, self.sum);
+        self.xk = self.xk * self.x;
+        self.terms += 1;
+        self.sum
+    }
+
+    /// Returns the sum of all terms added so far, or zero if none were added.
+    #[inline]
+    pub fn sum(&self) -> F {
+        self.sum
+    }
+
+    /// Returns the number of terms added so far, which is also the degree of the next term.
+    #[inline]
+    pub fn terms(&self) -> usize {
+        self.terms
+    }
+}
@@ -63,3 +63,3484 @@ fn test_polys() {
         }
     }
 }
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_poly_sorted() {
+    use fast_polynomial::accurate::poly_sorted;
+
+    // Taylor series of exp(x)
+    let mut c = [1.0f64; 40];
+    for k in 1..c.len() {
+        c[k] = c[k - 1] / k as f64;
+    }
+
+    for x in [-2.5f64, -0.5, 0.1, 0.5, 2.0] {
+        assert_feq!(1e-10, x.exp(), poly_sorted(x, &c));
+        assert_feq!(1e-10, horners_method(x, &c), poly_sorted(x, &c));
+    }
+
+    assert_eq!(poly_sorted(0.5, &[] as &[f64]), 0.0);
+    assert!(poly_sorted(f64::NAN, &c).is_nan());
+}
+
+#[cfg(all(
+    feature = "arch-fma",
+    any(
+        all(target_arch = "x86_64", target_feature = "fma"),
+        all(target_arch = "aarch64", target_feature = "neon")
+    )
+))]
+#[test]
+fn test_arch_fma() {
+    use fast_polynomial::{arch::Fused, poly_array, poly_array_t};
+    use num_traits::MulAdd;
+
+    // 1 + 2^-30 squared, minus the rounded square, is only non-zero when fused
+    let a = 1.0 + 2f64.powi(-30);
+    let c = -(a * a);
+    assert_ne!(a * a + c, a.mul_add(a, c));
+    assert_eq!(
+        MulAdd::mul_add(Fused(a), Fused(a), Fused(c)).0,
+        a.mul_add(a, c)
+    );
+
+    let a = 1.0 + 2f32.powi(-14);
+    let c = -(a * a);
+    assert_eq!(
+        MulAdd::mul_add(Fused(a), Fused(a), Fused(c)).0,
+        a.mul_add(a, c)
+    );
+
+    let coeffs = [
+        0.9066094402137101,
+        0.7030666449646632,
+        0.8062843184510005,
+        1.4354479997076703,
+        1.1700851966666594,
+    ];
+
+    for x in [-0.5, 0.1, 0.5, 0.9, 1.1, 1.5] {
+        assert_eq!(poly_array_t(Fused(x), &coeffs).0, poly_array(x, &coeffs));
+    }
+
+    // fused multiply-add is correctly rounded, so is identical to libm's at runtime
+    #[cfg(feature = "libm")]
+    {
+        let mut state = 0x5851_F42D_4C95_7F2D;
+
+        for _ in 0..10_000 {
+            let [a, b, c] = [(); 3].map(|_| {
+                (xorshift(&mut state) - 0.5) * 2f64.powi(xorshift(&mut state) as i32 * 40 - 20)
+            });
+
+            assert_eq!(
+                MulAdd::mul_add(Fused(a), Fused(b), Fused(c)).0.to_bits(),
+                libm::fma(a, b, c).to_bits()
+            );
+
+            let (a, b, c) = (a as f32, b as f32, c as f32);
+
+            assert_eq!(
+                MulAdd::mul_add(Fused(a), Fused(b), Fused(c)).0.to_bits(),
+                libm::fmaf(a, b, c).to_bits()
+            );
+        }
+
+        // the cancellation above, where an unfused result would differ
+        let a = 1.0 + 2f64.powi(-30);
+        assert_eq!(
+            MulAdd::mul_add(Fused(a), Fused(a), Fused(-(a * a))).0,
+            libm::fma(a, a, -(a * a))
+        );
+    }
+}
+
+#[test]
+fn test_poly_array_assert() {
+    use fast_polynomial::{poly_array, poly_array_assert};
+
+    let c = [
+        0.9066094402137101,
+        0.7030666449646632,
+        0.8062843184510005,
+        1.4354479997076703,
+        1.1700851966666594,
+    ];
+    let s = &c[..];
+
+    for x in [-0.5, 0.1, 0.5, 1.5] {
+        assert_eq!(poly_array(x, &c), unsafe {
+            poly_array_assert::<_, 5>(x, s)
+        });
+    }
+}
+
+#[cfg(any(debug_assertions, feature = "paranoid"))]
+#[test]
+#[should_panic]
+fn test_poly_array_assert_mismatch() {
+    let c = [1.0, 2.0, 3.0];
+    unsafe { fast_polynomial::poly_array_assert::<f64, 4>(0.5, &c) };
+}
+
+#[test]
+fn test_poly_hint() {
+    use fast_polynomial::{poly, poly_hint};
+
+    let c: Vec<f64> = (0..40).map(|i| 1.0 / (i + 1) as f64).collect();
+
+    for n in 0..=c.len() {
+        for x in [-1.5, -0.5, 0.25, 0.9] {
+            assert_eq!(unsafe { poly_hint(x, &c[..n], n) }, poly(x, &c[..n]));
+        }
+    }
+}
+
+#[cfg(any(debug_assertions, feature = "paranoid"))]
+#[test]
+#[should_panic]
+fn test_poly_hint_mismatch() {
+    unsafe { fast_polynomial::poly_hint(0.5, &[1.0, 2.0, 3.0], 12) };
+}
+
+#[test]
+fn test_barycentric() {
+    use fast_polynomial::bases::{bary_eval, bary_weights};
+
+    let c = [0.5, -1.25, 0.75, 2.0, -0.125];
+
+    // Chebyshev points of the second kind
+    let xs: Vec<f64> = (0..c.len())
+        .map(|j| (std::f64::consts::PI * j as f64 / (c.len() - 1) as f64).cos())
+        .collect();
+    let ys: Vec<f64> = xs.iter().map(|&x| horners_method(x, &c)).collect();
+
+    let mut w = vec![0.0; xs.len()];
+    bary_weights(&xs, &mut w);
+
+    for x in [-0.9, -0.3, 0.0, 0.2, 0.7, 1.5] {
+        assert_feq!(1e-12, horners_method(x, &c), bary_eval(&xs, &ys, &w, x));
+    }
+
+    // exactly on a node
+    assert_eq!(ys[2], bary_eval(&xs, &ys, &w, xs[2]));
+}
+
+/// Simple xorshift generator to produce reproducible coefficients without extra dependencies
+fn xorshift(state: &mut u64) -> f64 {
+    *state ^= *state << 13;
+    *state ^= *state >> 7;
+    *state ^= *state << 17;
+    (*state >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[test]
+fn test_poly_array_matches_poly() {
+    use fast_polynomial::poly_array;
+
+    macro_rules! check_lengths {
+        ($($n:literal)*) => {$({
+            let mut state = 0x2545_F491_4F6C_DD1D_u64 ^ $n;
+
+            for _ in 0..10 {
+                let c: [f64; $n] = core::array::from_fn(|_| xorshift(&mut state) * 2.0 - 1.0);
+
+                for x in [-1.5, -1.0, -0.7, -0.1, 0.0, 0.3, 0.9, 1.0, 1.2] {
+                    // both paths share the same dispatch, so should be bit-identical
+                    assert_eq!(poly_array(x, &c).to_bits(), poly(x, &c).to_bits(), "N = {}, x = {}", $n, x);
+                    assert_feq!(1e-9 * (1.0 + horners_method(x, &c).abs()), horners_method(x, &c), poly(x, &c));
+                }
+            }
+        })*};
+    }
+
+    check_lengths!(
+         0  1  2  3  4  5  6  7  8  9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32
+        33 34 35 36 37 38 39 40 41 42 43 44 45 46 47 48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63 64
+    );
+}
+
+#[test]
+fn test_rational_reciprocal_boundary() {
+    use fast_polynomial::rational_array;
+
+    fn reference(x: f64, n: &[f64], d: &[f64]) -> f64 {
+        horners_method(x, n) / horners_method(x, d)
+    }
+
+    macro_rules! check_degrees {
+        ($(($p:literal, $q:literal))*) => {$({
+            let mut state = 0x9E37_79B9_7F4A_7C15_u64 ^ ($p * 31 + $q);
+
+            let n: [f64; $p] = core::array::from_fn(|_| xorshift(&mut state) * 2.0 - 1.0);
+            // dominant constant term to keep the denominator away from zero for |x| near 1
+            let d: [f64; $q] = core::array::from_fn(|i| if i == 0 { 4.0 } else { xorshift(&mut state) * 0.5 });
+
+            for x in [-1.001, -1.0, -0.999, 0.999, 1.0, 1.001] {
+                let expected = reference(x, &n, &d);
+                let tolerance = 1e-12 * (1.0 + expected.abs());
+
+                assert_feq!(tolerance, expected, rational(x, &n, &d));
+                assert_feq!(tolerance, expected, rational_array(x, &n, &d));
+            }
+
+            // continuity across the point of inversion
+            for s in [-1.0, 1.0] {
+                let below = rational(s * (1.0 - 1e-9), &n, &d);
+                let above = rational(s * (1.0 + 1e-9), &n, &d);
+                assert_feq!(1e-6, below, above);
+
+                let below = rational_array(s * (1.0 - 1e-9), &n, &d);
+                let above = rational_array(s * (1.0 + 1e-9), &n, &d);
+                assert_feq!(1e-6, below, above);
+            }
+        })*};
+    }
+
+    check_degrees!(
+        // P < Q
+        (1, 2) (1, 5) (2, 3) (3, 4) (3, 8) (4, 7) (5, 16) (1, 17)
+        // P > Q
+        (2, 1) (5, 1) (3, 2) (4, 3) (8, 3) (7, 4) (16, 5) (17, 1)
+        // P == Q
+        (1, 1)(2, 2)(3, 3)(4, 4)(7, 7)(16, 16)(17, 17)
+    );
+
+    // empty numerator and denominator is 0/0 regardless of the branch taken
+    for x in [-1.001, 0.999, 1.001] {
+        assert!(rational_array::<f64, 0, 0>(x, &[], &[]).is_nan());
+        assert!(rational(x, &[], &[]).is_nan());
+    }
+
+    // empty numerator is zero on both sides of the boundary
+    for x in [-1.001, 0.999, 1.001] {
+        assert_eq!(0.0, rational_array(x, &[], &[4.0, 0.5, 0.25]));
+        assert_eq!(0.0, rational(x, &[], &[4.0, 0.5, 0.25]));
+    }
+}
+
+#[test]
+fn test_affine() {
+    use fast_polynomial::{algebra::compose_affine, poly_affine};
+
+    let c = [0.5, -1.25, 0.75, 2.0, -0.125, 0.3];
+
+    for (a, b) in [(1.0, 0.0), (2.0, -1.0), (0.5, 0.25), (-1.5, 3.0)] {
+        let mut composed = c;
+        compose_affine(&mut composed, a, b);
+
+        for x in [-1.5, -0.3, 0.0, 0.2, 0.7, 1.5] {
+            let expected = horners_method(a * x + b, &c);
+            assert_feq!(1e-10, expected, poly_affine(x, a, b, &c));
+            assert_feq!(1e-10 * (1.0 + expected.abs()), expected, poly(x, &composed));
+        }
+    }
+}
+
+#[test]
+fn test_rational_nan() {
+    let n = [0.5, -1.25, 0.75, 2.0];
+    let d = [4.0, 0.5, 0.25];
+
+    assert!(rational(f64::NAN, &n, &d).is_nan());
+    assert!(rational(f64::NAN, &n[..2], &d[..2]).is_nan());
+    assert!(fast_polynomial::rational_array(f64::NAN, &n, &d).is_nan());
+}
+
+#[test]
+fn test_nan_propagation() {
+    use fast_polynomial::{
+        accurate::{poly_array_dd_acc, poly_stable},
+        poly_array, poly_array_no_contract, poly_array_t, poly_array_with_powers, poly_auto,
+        poly_coeffs, poly_f, poly_f_n, poly_hint, poly_permuted, poly_prefetch, poly_strided,
+        poly_vec, rational_array, rational_coeffs, rational_f,
+        streaming::PolyAccumulator,
+    };
+
+    let nan = f64::NAN;
+
+    let mut state = 0x3c6ef372fe94f82b;
+    let c: [f64; 70] = core::array::from_fn(|_| xorshift(&mut state) - 0.5);
+
+    // NaN inputs propagate for any polynomial with at least two coefficients
+    macro_rules! check_lengths {
+        ($($n:literal)*) => {$({
+            let c: &[f64; $n] = c[..$n].try_into().unwrap();
+
+            assert!(poly_array(nan, c).is_nan(), "N = {}", $n);
+            assert!(poly_array_t(nan, c).is_nan(), "N = {}", $n);
+            assert!(poly_array_no_contract(nan, c).is_nan(), "N = {}", $n);
+            assert!(poly_array_with_powers(nan, c).0.is_nan(), "N = {}", $n);
+            assert!(poly_array_dd_acc(nan, c).is_nan(), "N = {}", $n);
+            assert!(poly_f_n::<_, _, $n>(nan, |i| c[i]).is_nan(), "N = {}", $n);
+        })*};
+    }
+
+    check_lengths!(2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 23 31 32 33 47 48 63 64 65 70);
+
+    for n in 2..=c.len() {
+        let c = &c[..n];
+
+        assert!(poly(nan, c).is_nan(), "n = {n}");
+        assert!(poly_auto(nan, c).is_nan(), "n = {n}");
+        assert!(poly_vec(nan, c).is_nan(), "n = {n}");
+        assert!(poly_coeffs(nan, c).is_nan(), "n = {n}");
+        assert!(poly_prefetch(nan, c).is_nan(), "n = {n}");
+        assert!(poly_stable(nan, c).is_nan(), "n = {n}");
+        assert!(poly_f(nan, n, |i| c[i]).is_nan(), "n = {n}");
+        assert!(poly_strided(nan, c, 1, n).is_nan(), "n = {n}");
+        assert!(unsafe { poly_hint(nan, c, n) }.is_nan(), "n = {n}");
+
+        let perm: Vec<usize> = (0..n).rev().collect();
+        assert!(poly_permuted(nan, c, &perm).is_nan(), "n = {n}");
+
+        let mut acc = PolyAccumulator::new(nan);
+        for chunk in c.rchunks(7) {
+            acc.push(chunk);
+        }
+        assert!(acc.finalize().is_nan(), "n = {n}");
+
+        // even if every coefficient but the constant term is zero
+        let mut z = vec![0.0; n];
+        z[0] = 1.0;
+        assert!(poly(nan, &z).is_nan(), "n = {n}");
+    }
+
+    // NaN coefficients propagate for any input
+    for x in [-2.0, -0.5, 0.0, 0.5, 2.0] {
+        for n in 1..=c.len() {
+            for k in [0, n / 2, n - 1] {
+                let mut c = c[..n].to_vec();
+                c[k] = nan;
+
+                assert!(poly(x, &c).is_nan(), "n = {n}, k = {k}, x = {x}");
+                assert!(poly_stable(x, &c).is_nan(), "n = {n}, k = {k}, x = {x}");
+            }
+        }
+    }
+
+    // rational functions, for which NaN skips the reciprocal path
+    for (p, q) in [
+        (1, 2),
+        (2, 1),
+        (2, 2),
+        (3, 5),
+        (5, 3),
+        (8, 8),
+        (17, 17),
+        (33, 20),
+    ] {
+        let (n, d) = (&c[..p], &c[c.len() - q..]);
+
+        assert!(rational(nan, n, d).is_nan(), "p = {p}, q = {q}");
+        assert!(rational_coeffs(nan, n, d).is_nan(), "p = {p}, q = {q}");
+        assert!(
+            rational_f(nan, p, q, |i| n[i], |i| d[i]).is_nan(),
+            "p = {p}, q = {q}"
+        );
+    }
+
+    let (n, d): (&[f64; 4], &[f64; 6]) = (c[..4].try_into().unwrap(), c[..6].try_into().unwrap());
+    assert!(rational_array(nan, n, d).is_nan());
+    assert!(rational_array(nan, d, n).is_nan());
+
+    // constant polynomials and empty polynomials never depend on x
+    assert_eq!(poly(nan, &[1.5]), 1.5);
+    assert_eq!(poly(nan, &[]), 0.0);
+    assert_eq!(rational(nan, &[1.5], &[3.0]), 0.5);
+    assert_eq!(fast_polynomial::poly_symmetric(nan, &[1.5], true), 1.5);
+}
+
+#[test]
+fn test_array_wrap_family() {
+    use fast_polynomial::{many_xs::ArrayWrap, poly_array};
+    use num_traits::MulAdd;
+
+    let c = [0.5, -1.25, 0.75, 2.0, -0.125, 0.3, 1.1, -0.7, 0.2];
+
+    // 4 polynomials, each a scaled version of `c`
+    let family = c.map(|c| ArrayWrap([c, 2.0 * c, -c, 0.5 * c]));
+
+    for x in [-0.9, 0.0, 0.4, 1.3] {
+        let ys = poly_array(ArrayWrap::splat(x), &family);
+        let y = horners_method(x, &c);
+
+        for (lane, s) in [1.0, 2.0, -1.0, 0.5].into_iter().enumerate() {
+            assert_feq!(1e-12, s * y, ys.0[lane]);
+        }
+
+        // many xs for a single polynomial
+        let xs = ArrayWrap([x, -x, 0.5 * x, 0.0]);
+        let ys = poly_array(xs, &c.map(ArrayWrap::splat));
+
+        for lane in 0..4 {
+            assert_feq!(1e-12, horners_method(xs.0[lane], &c), ys.0[lane]);
+        }
+    }
+
+    let a = ArrayWrap([1.0, 2.0]);
+    assert_eq!(
+        a.mul_add(3.0, ArrayWrap([0.5, 0.25])),
+        ArrayWrap([3.5, 6.25])
+    );
+    assert_eq!(a * 2.0, ArrayWrap([2.0, 4.0]));
+}
+
+#[test]
+fn test_poly_array_cond() {
+    use fast_polynomial::diagnostics::poly_array_cond;
+
+    // (1 - x)^4 has heavy cancellation near x = 1
+    let c = [1.0, -4.0, 6.0, -4.0, 1.0];
+
+    let (y, abs_sum) = poly_array_cond(0.99, &c);
+    assert_feq!(1e-12, (1.0f64 - 0.99).powi(4), y);
+    assert_feq!(1e-12, (1.0f64 + 0.99).powi(4), abs_sum);
+    assert!(abs_sum / y.abs() > 1e8);
+
+    // all positive terms have a condition number of one
+    let (y, abs_sum) = poly_array_cond(0.5, &[1.0, 2.0, 3.0]);
+    assert_eq!(y, abs_sum);
+}
+
+#[test]
+fn test_high_degree() {
+    let mut state = 0x1234_5678_9ABC_DEF0_u64;
+    let c: Vec<f64> = (0..300).map(|_| xorshift(&mut state) * 2.0 - 1.0).collect();
+
+    for x in [-0.99, -0.5, 0.1, 0.5, 0.99] {
+        for n in [63, 64, 65, 79, 80, 127, 128, 129, 143, 191, 200, 256, 300] {
+            let expected = horners_method(x, &c[..n]);
+            assert_feq!(1e-12 * (1.0 + expected.abs()), expected, poly(x, &c[..n]));
+        }
+    }
+}
+
+#[test]
+fn test_array_wrap_scalar_ops() {
+    use fast_polynomial::many_xs::ArrayWrap;
+
+    let a = ArrayWrap([1.0f32, 2.0, 4.0]);
+
+    assert_eq!(2.0 * a, a * 2.0);
+    assert_eq!(1.0 + a, a + 1.0);
+    assert_eq!(1.0 - a, ArrayWrap([0.0, -1.0, -3.0]));
+    assert_eq!(a - 1.0, ArrayWrap([0.0, 1.0, 3.0]));
+    assert_eq!(4.0 / a, ArrayWrap([4.0, 2.0, 1.0]));
+    assert_eq!(a - a, ArrayWrap([0.0; 3]));
+
+    let b = ArrayWrap([3i32, 5]);
+    assert_eq!(10 - b, ArrayWrap([7, 5]));
+}
+
+#[test]
+fn test_array_wrap_reduce() {
+    use fast_polynomial::{many_xs::ArrayWrap, poly_array};
+
+    let xs = ArrayWrap([0.0f64, 0.25, 0.5, 0.75, 1.0, -0.5, -1.0, 2.0]);
+    let c = [1.0, 0.3, 0.4, 1.6];
+
+    let ys = poly_array(xs, &c.map(ArrayWrap::splat));
+
+    let sum: f64 = xs.0.iter().map(|&x| horners_method(x, &c)).sum();
+    assert_feq!(1e-12, sum, ys.reduce_sum());
+
+    let product: f64 = xs.0.iter().map(|&x| horners_method(x, &c)).product();
+    assert_feq!(1e-12, product, ys.reduce_product());
+
+    assert_feq!(1e-12, horners_method(2.0, &c), ys.reduce_max());
+
+    assert_eq!(ArrayWrap([1.0, f64::NAN, 3.0]).reduce_max(), 3.0);
+    assert_eq!(ArrayWrap::<0, f64>([]).reduce_sum(), 0.0);
+    assert_eq!(ArrayWrap::<0, f64>([]).reduce_product(), 1.0);
+}
+
+#[test]
+fn test_sparse_poly() {
+    use fast_polynomial::sparse_poly;
+
+    for x in [-1.5f64, -0.5, 0.0, 0.3, 1.0, 2.0] {
+        let expected = horners_method(x, &[0.5, 0.0, 0.0, -1.25, 0.0, 0.0, 0.0, 0.75]);
+
+        assert_feq!(
+            1e-12,
+            expected,
+            sparse_poly!(x; 0 => 0.5, 3 => -1.25, 7 => 0.75)
+        );
+        assert_feq!(
+            1e-12,
+            expected,
+            sparse_poly!(x; 7 => 0.75, 0 => 0.5, 3 => -1.25,)
+        );
+    }
+
+    assert_eq!(sparse_poly!(2.0f32; 10 => 1.0), 1024.0);
+    assert_eq!(sparse_poly!(3i64; 0 => 4, 2 => 1), 13);
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+#[test]
+fn test_roots() {
+    use fast_polynomial::roots::*;
+
+    fn assert_roots(expected: &[f64], actual: &[f64]) {
+        assert_eq!(
+            expected.len(),
+            actual.len(),
+            "{:?} != {:?}",
+            expected,
+            actual
+        );
+        for (e, a) in expected.iter().zip(actual) {
+            assert_feq!(1e-9, *e, *a);
+        }
+    }
+
+    assert_roots(&[2.0], &roots_linear(2.0, -4.0));
+    assert_roots(&[], &roots_linear(0.0, 1.0));
+
+    // quadratic: distinct, double, complex, degenerate
+    assert_roots(&[1.0, 2.0], &roots_quadratic(1.0, -3.0, 2.0));
+    assert_roots(&[-1.0, -1.0], &roots_quadratic(1.0, 2.0, 1.0));
+    assert_roots(&[], &roots_quadratic(1.0, 0.0, 1.0));
+    assert_roots(&[0.0, 0.0], &roots_quadratic(3.0, 0.0, 0.0));
+    assert_roots(&[-0.5], &roots_quadratic(0.0, 2.0, 1.0));
+
+    // the small root would be lost entirely to cancellation with the naive formula
+    let r = roots_quadratic(1.0, 1e8, 1.0);
+    assert_eq!(r.len(), 2);
+    assert!((r[1] - -1e-8f64).abs() < 1e-20);
+
+    // cubic: three distinct, one real, triple, single + double
+    assert_roots(&[1.0, 2.0, 3.0], &roots_cubic(1.0, -6.0, 11.0, -6.0));
+    assert_roots(&[1.0, 2.0, 3.0], &roots_cubic(-2.0, 12.0, -22.0, 12.0));
+    assert_roots(&[1.0], &roots_cubic(1.0, -1.0, 1.0, -1.0));
+    assert_roots(&[1.0, 1.0, 1.0], &roots_cubic(1.0, -3.0, 3.0, -1.0));
+    assert_roots(&[-2.0, 1.0, 1.0], &roots_cubic(1.0, 0.0, -3.0, 2.0));
+    assert_roots(&[0.0], &roots_cubic(1.0, 0.0, 1.0, 0.0));
+
+    // quartic: four distinct, biquadratic, two real, none
+    assert_roots(
+        &[1.0, 2.0, 3.0, 4.0],
+        &roots_quartic(1.0, -10.0, 35.0, -50.0, 24.0),
+    );
+    assert_roots(
+        &[-2.0, -1.0, 1.0, 2.0],
+        &roots_quartic(1.0, 0.0, -5.0, 0.0, 4.0),
+    );
+    assert_roots(&[-1.0, 1.0], &roots_quartic(1.0, 0.0, 0.0, 0.0, -1.0));
+    assert_roots(&[], &roots_quartic(1.0, 0.0, 0.0, 0.0, 1.0));
+    // (x - 0.5)(x + 3)(x² + x + 1)
+    assert_roots(&[-3.0, 0.5], &roots_quartic(1.0, 3.5, 2.0, 1.0, -1.5));
+
+    for &(a, b, c, d, e) in &[(2.0f64, -3.0, -7.5, 1.25, 0.5), (0.5, 1.0, -4.0, -2.0, 3.0)] {
+        for x in roots_quartic(a, b, c, d, e).iter() {
+            assert_feq!(1e-9, 0.0, horners_method(*x, &[e, d, c, b, a]));
+        }
+    }
+}
+
+#[test]
+fn test_double_double() {
+    use fast_polynomial::{accurate::DoubleDouble, poly_array, rational_array};
+
+    let dd = DoubleDouble::from;
+
+    // exact cancellation of a tiny value
+    let tiny = 2f64.powi(-60);
+    assert_eq!((dd(1.0) + dd(tiny) - dd(1.0)).to_f64(), tiny);
+
+    // (x - 1)^4 = 2^-80 exactly at x = 1 + 2^-20, and every intermediate fits within 106 bits
+    let c = [1.0, -4.0, 6.0, -4.0, 1.0];
+    let x = 1.0 + 2f64.powi(-20);
+    assert_eq!(poly_array(dd(x), &c.map(dd)).to_f64(), 2f64.powi(-80));
+    assert_ne!(poly_array(x, &c), 2f64.powi(-80));
+
+    // 1/3 * 3 recovers one to well beyond f64 precision
+    let third = dd(1.0) / dd(3.0);
+    let one = third * dd(3.0);
+    assert!((one - dd(1.0)).to_f64().abs() < 1e-30);
+
+    // agrees with f64 evaluation for well-conditioned polynomials
+    let n = [0.5, -1.25, 0.75, 2.0];
+    let d = [4.0, 0.5, 0.25];
+
+    for x in [-1.5, -0.5, 0.3, 0.9, 2.0] {
+        let expected = rational_array(x, &n, &d);
+        assert_feq!(
+            1e-14,
+            expected,
+            rational_array(dd(x), &n.map(dd), &d.map(dd)).to_f64()
+        );
+    }
+}
+
+#[test]
+fn test_double_double_exact() {
+    use fast_polynomial::{accurate::DoubleDouble, poly_array};
+
+    // `v * 2^scale` as an integer, if it is one
+    fn scaled(v: f64, scale: i32) -> Option<i128> {
+        let s = v * 2f64.powi(scale);
+        (s.fract() == 0.0 && s.abs() < 2f64.powi(126)).then_some(s as i128)
+    }
+
+    let mut state = 0xD0B1_E0D0_B1E0_0119;
+    let mut int =
+        |bits: u32| (xorshift(&mut state) * 2f64.powi(bits as i32 + 1)) as i128 - (1 << bits);
+
+    // Polynomials with dyadic coefficients `k_i / 2^24` at `x = m / 2^8`, whose exact values are
+    // integers scaled by `2^-104` for degree 10, small enough to be computed exactly with `i128`.
+    let mut checked = 0;
+
+    for _ in 0..2000 {
+        let m = int(8);
+        let k: [i128; 11] = core::array::from_fn(|_| int(24));
+
+        let (mut exact, mut magnitude) = (0i128, 0.0);
+
+        for (i, &k) in k.iter().enumerate() {
+            let term = (k * m.pow(i as u32)) << (8 * (10 - i));
+            exact += term;
+            magnitude += (term as f64).abs();
+        }
+
+        let x = DoubleDouble::from(m as f64 / 256.0);
+        let y = poly_array(x, &k.map(|k| DoubleDouble::from(k as f64 / 2f64.powi(24))));
+
+        // the residual of the leading component is exactly representable, compared against the trailing one
+        let Some(hi) = scaled(y.hi(), 104) else {
+            continue;
+        };
+        let error = ((exact - hi) as f64 - y.lo() * 2f64.powi(104)).abs();
+
+        assert!(
+            error <= 2f64.powi(-100) * magnitude,
+            "{error:e} > 2^-100 * {magnitude:e}"
+        );
+        checked += 1;
+    }
+
+    assert!(checked > 1900);
+
+    // division of integers `a / b`, checking the residual `q b - a` exactly, relative to `a`
+    let mut checked = 0;
+
+    for _ in 0..2000 {
+        let (a, b) = (int(20), int(10));
+
+        if a == 0 || b == 0 {
+            continue;
+        }
+
+        let q = DoubleDouble::from(a as f64) / DoubleDouble::from(b as f64);
+        let scale = 115 - q.hi().abs().log2().floor() as i32;
+
+        let (Some(hi), Some(lo)) = (scaled(q.hi(), scale), scaled(q.lo(), scale)) else {
+            continue;
+        };
+
+        let residual = (hi + lo) * b - (a << scale);
+
+        assert!(
+            (residual as f64).abs() <= 2f64.powi(-100) * (a as f64).abs() * 2f64.powi(scale),
+            "{a} / {b}"
+        );
+        checked += 1;
+    }
+
+    assert!(checked > 1900);
+}
+
+#[test]
+fn test_streaming() {
+    use fast_polynomial::streaming::PolyAccumulator;
+
+    let mut state = 0x2545F4914F6CDD1D;
+    let coeffs: Vec<f64> = (0..1000).map(|_| xorshift(&mut state) - 0.5).collect();
+
+    for x in [-1.01, -0.5, 0.0, 0.25, 0.999, 1.0] {
+        let expected = horners_method(x, &coeffs);
+
+        for chunk_size in [1, 3, 16, 17, 64, 1000, 2000] {
+            let mut acc = PolyAccumulator::new(x);
+            for chunk in coeffs.rchunks(chunk_size) {
+                acc.push(chunk);
+            }
+
+            assert_feq!(1e-12 * expected.abs().max(1.0), expected, acc.finalize());
+        }
+
+        // uneven chunk sizes, including empty chunks
+        let mut acc = PolyAccumulator::new(x);
+        let (head, tail) = coeffs.split_at(389);
+        for chunk in tail.rchunks(100) {
+            acc.push(chunk);
+            acc.push(&[]);
+        }
+        for chunk in head.rchunks(7) {
+            acc.push(chunk);
+        }
+
+        assert_feq!(1e-12 * expected.abs().max(1.0), expected, acc.finalize());
+    }
+
+    assert_eq!(PolyAccumulator::new(2.0f64).finalize(), 0.0);
+}
+
+#[test]
+fn test_powi() {
+    use fast_polynomial::powi;
+
+    for e in 0..64 {
+        assert_eq!(powi(2u64, e), 1u64 << e);
+        assert_eq!(powi(-1i32, e), if e % 2 == 0 { 1 } else { -1 });
+        assert_eq!(powi(2.0f64, e), 2f64.powi(e as i32));
+    }
+
+    for e in 0..40 {
+        let x = 1.0137f64;
+        assert_feq!(1e-14, x.powi(e as i32), powi(x, e));
+    }
+
+    assert_eq!(powi(0.0f64, 0), 1.0);
+    assert_eq!(powi(0.0f64, 5), 0.0);
+    assert_eq!(powi(3u128, 80), 3u128.pow(80));
+}
+
+#[test]
+fn test_compose() {
+    use fast_polynomial::{algebra::compose, poly};
+
+    let p = [0.5, -1.0, 2.0, 0.25];
+    let q = [1.0, 0.3, -0.7];
+
+    let mut out = [0.0; 7];
+    compose(&p, &q, &mut out);
+
+    for x in [-2.0f64, -0.5, 0.0, 0.3, 1.0, 1.7] {
+        assert_feq!(1e-12, poly(poly(x, &q), &p), poly(x, &out));
+    }
+
+    // composing with x is the identity
+    let mut out = [0.0; 4];
+    compose(&p, &[0.0, 1.0], &mut out);
+    assert_eq!(out, p);
+
+    // constant q gives a constant
+    let mut out = [0.0; 1];
+    compose(&p, &[2.0], &mut out);
+    assert_eq!(out[0], poly(2.0, &p));
+
+    let mut out = [1.0; 1];
+    compose::<f64>(&[], &q, &mut out);
+    assert_eq!(out[0], 0.0);
+}
+
+#[cfg(feature = "rounding")]
+#[test]
+fn test_rounding() {
+    use fast_polynomial::{
+        accurate::DoubleDouble,
+        poly_array,
+        rounding::{poly_array_round_down, poly_array_round_up},
+    };
+
+    let mut state = 0x9E3779B97F4A7C15;
+
+    for _ in 0..1000 {
+        let c: [f64; 9] = core::array::from_fn(|_| xorshift(&mut state) * 4.0 - 2.0);
+        let x = xorshift(&mut state) * 4.0 - 2.0;
+
+        let lo = poly_array_round_down(x, &c);
+        let hi = poly_array_round_up(x, &c);
+
+        // double-double is exact enough to order correctly against the bounds
+        let exact = poly_array(DoubleDouble::from(x), &c.map(DoubleDouble::from));
+
+        assert!(DoubleDouble::from(lo) <= exact, "{lo} > {exact:?}");
+        assert!(exact <= DoubleDouble::from(hi), "{hi} < {exact:?}");
+        // the width grows with the magnitude of the terms, which is |exact| without cancellation
+        let magnitude = poly_array(x.abs(), &c.map(f64::abs));
+        assert!(hi - lo <= 1e-14 * magnitude, "{lo} {hi}");
+    }
+
+    // the previous rounding mode is restored, checked at runtime rather than constant-folded
+    use core::hint::black_box;
+
+    let tiny = 2f64.powi(-60);
+
+    assert_eq!(poly_array_round_up(1.0, &[1.0, tiny]), 1.0 + f64::EPSILON);
+    assert_eq!(black_box(1.0) + black_box(tiny), 1.0);
+
+    assert_eq!(
+        poly_array_round_down(-1.0, &[-1.0, tiny]),
+        -1.0 - f64::EPSILON
+    );
+    assert_eq!(black_box(-1.0) - black_box(tiny), -1.0);
+}
+
+#[test]
+fn test_coefficients() {
+    use fast_polynomial::{poly, poly_coeffs, rational, rational_coeffs, Coefficients};
+
+    struct Strided<'a>(&'a [f64]);
+
+    impl Coefficients<f64> for Strided<'_> {
+        fn len(&self) -> usize {
+            self.0.len() / 2
+        }
+
+        fn coeff(&self, i: usize) -> f64 {
+            self.0[i * 2]
+        }
+    }
+
+    let c = [1.0, 0.3, 0.4, 1.6, -0.2, 0.7];
+    #[cfg(feature = "alloc")]
+    let v = c.to_vec();
+    let n = [0.5, 1.0, -0.25];
+
+    for x in [-2.0f64, -0.5, 0.0, 0.5, 2.0] {
+        let expected = poly(x, &c);
+
+        assert_eq!(expected, poly_coeffs(x, c));
+        assert_eq!(expected, poly_coeffs(x, &c[..]));
+
+        #[cfg(feature = "alloc")]
+        {
+            assert_eq!(expected, poly_coeffs(x, &v));
+            assert_eq!(expected, poly_coeffs(x, v.clone()));
+            assert_eq!(rational(x, &n, &c), rational_coeffs(x, n, &v));
+        }
+
+        assert_eq!(poly(x, &[1.0, 0.4, -0.2]), poly_coeffs(x, Strided(&c)));
+        assert_eq!(rational(x, &n, &c), rational_coeffs(x, n, &c[..]));
+    }
+
+    assert_eq!(poly_coeffs(2.0, [0.0f64; 0]), 0.0);
+    assert!(Coefficients::<f64>::is_empty(&Strided(&[1.0])));
+
+    // an incorrect `LEN` is ignored
+    struct Mislabeled<'a>(&'a [f64]);
+
+    impl Coefficients<f64> for Mislabeled<'_> {
+        const LEN: usize = 64;
+
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        fn coeff(&self, i: usize) -> f64 {
+            self.0[i]
+        }
+    }
+
+    assert_eq!(poly_coeffs(0.5, Mislabeled(&c)), poly(0.5, &c));
+    assert_eq!(
+        rational_coeffs(0.5, Mislabeled(&n), Mislabeled(&c)),
+        rational(0.5, &n, &c)
+    );
+}
+
+#[test]
+fn test_poly_array_dominant() {
+    use fast_polynomial::{diagnostics::poly_array_dominant, poly_array};
+
+    let c = [1.0, -3.0, 0.5, 0.01];
+
+    for (x, k) in [
+        (0.0, 0),
+        (0.1, 0),
+        (0.5, 1),
+        (-0.5, 1),
+        (7.0, 2),
+        (-100.0, 3),
+    ] {
+        let (y, dominant) = poly_array_dominant(x, &c);
+
+        assert_eq!(y, poly_array(x, &c));
+        assert_eq!(dominant, k, "x = {x}");
+    }
+
+    // ties favor the lowest index
+    assert_eq!(poly_array_dominant(1.0, &[2.0, -2.0, 2.0]).1, 0);
+    assert_eq!(poly_array_dominant(1.0, &[0.0f64; 0]), (0.0, 0));
+    assert_eq!(poly_array_dominant(1.0, &[f64::NAN, 1.0]).1, 1);
+}
+
+#[test]
+fn test_fill_powers() {
+    use fast_polynomial::{fill_powers, powi};
+
+    let mut out = [0u64; 64];
+    fill_powers(2, &mut out);
+    for (k, &p) in out.iter().enumerate() {
+        assert_eq!(p, 1u64 << k);
+    }
+
+    let x = 0.9137f64;
+    let mut out = [0.0; 20];
+    fill_powers(x, &mut out);
+    for (k, &p) in out.iter().enumerate() {
+        assert_feq!(1e-14, powi(x, k as u32), p);
+    }
+
+    fill_powers(x, &mut []);
+}
+
+#[test]
+fn test_try_poly_f() {
+    use fast_polynomial::{poly, try_poly_f};
+
+    let c: Vec<f64> = (0..50).map(|i| 1.0 / (i + 1) as f64).collect();
+
+    for n in 0..50 {
+        let y: Result<f64, ()> = try_poly_f(0.7, n, |i| Ok(c[i]));
+        assert_eq!(y, Ok(poly(0.7, &c[..n])));
+
+        // every coefficient is requested exactly once
+        let mut requested = vec![0; n];
+        let _ = try_poly_f(0.7, n, |i| {
+            requested[i] += 1;
+            Ok::<_, ()>(c[i])
+        });
+        assert!(requested.iter().all(|&k| k == 1));
+    }
+
+    for fail in [0, 3, 17, 49] {
+        let mut calls_after = 0;
+        let mut failed = false;
+
+        let y = try_poly_f(0.7, 50, |i| {
+            if failed {
+                calls_after += 1;
+            }
+
+            if i == fail {
+                failed = true;
+                Err(i)
+            } else {
+                Ok(c[i])
+            }
+        });
+
+        assert_eq!(y, Err(fail));
+        assert_eq!(calls_after, 0);
+    }
+
+    // the multi-accumulator feature only changes the order of operations of `poly` from 64 coefficients
+    #[cfg(not(feature = "multi-accumulator"))]
+    {
+        let c: Vec<f64> = (0..200).map(|i| 1.0 / (i + 1) as f64).collect();
+
+        for n in [64, 65, 100, 143, 200] {
+            let y: Result<f64, ()> = try_poly_f(0.99, n, |i| Ok(c[i]));
+            assert_eq!(y, Ok(poly(0.99, &c[..n])));
+        }
+    }
+
+    // blocks are requested from the highest degree, so an error there stops before the lower blocks
+    let mut lowest = usize::MAX;
+    let y = try_poly_f(0.7, 50, |i| {
+        lowest = lowest.min(i);
+        if i == 40 {
+            Err(i)
+        } else {
+            Ok(c[i])
+        }
+    });
+
+    assert_eq!(y, Err(40));
+    assert_eq!(lowest, 34);
+}
+
+#[test]
+fn test_array_wrap_refs() {
+    use fast_polynomial::{many_xs::ArrayWrap, poly_array};
+
+    let mut xs = [0.0f32, 0.25, 0.5, 0.75];
+    let c = [1.0, 0.3, 0.4, 1.6].map(ArrayWrap::splat);
+
+    let ys = poly_array(*ArrayWrap::from_ref(&xs), &c);
+    assert_eq!(ys.as_array(), &poly_array(ArrayWrap(xs), &c).0);
+
+    let w = ArrayWrap::from_mut(&mut xs);
+    *w *= 2.0;
+    w.as_mut_array()[0] = 1.0;
+
+    assert_eq!(xs, [1.0, 0.5, 1.0, 1.5]);
+}
+
+#[test]
+fn test_poly_stable() {
+    use fast_polynomial::{accurate::poly_stable, poly};
+
+    // every term is exactly one at x = 2^64, but x^16 overflows
+    let x = 2f64.powi(64);
+    // 2^-1024 is the smallest subnormal power of 2^-64
+    for n in 0..=17 {
+        let c: Vec<f64> = (0..n).map(|k| 0.5f64.powi(64 * k)).collect();
+        assert_eq!(poly_stable(x, &c), n as f64);
+    }
+
+    // the terms are still finite up to 33 coefficients, but x^16 overflows
+    let c: [f64; 33] = core::array::from_fn(|k| 0.5f64.powi(64 * k as i32).max(f64::from_bits(1)));
+    assert!(!poly(x, &c).is_finite());
+    assert!(poly_stable(x, &c).is_finite());
+
+    let c = [
+        0.9066094402137101,
+        0.7030666449646632,
+        0.8062843184510005,
+        1.4354479997076703,
+    ];
+    for x in [-1.5, -0.3, 0.0, 0.7, 2.0] {
+        assert_feq!(1e-14, horners_method(x, &c), poly_stable(x, &c));
+    }
+}
+
+#[test]
+fn test_degree() {
+    use fast_polynomial::algebra::{degree, leading_coeff};
+
+    assert_eq!(degree::<f64>(&[]), None);
+    assert_eq!(degree(&[0.0, 0.0]), None);
+    assert_eq!(degree(&[3.0]), Some(0));
+    assert_eq!(degree(&[1.0, 0.0, -2.0, 0.0, 0.0]), Some(2));
+    assert_eq!(degree(&[0, 0, 0, 7]), Some(3));
+
+    assert_eq!(leading_coeff::<f64>(&[]), None);
+    assert_eq!(leading_coeff(&[0.0, -0.0]), None);
+    assert_eq!(leading_coeff(&[1.0, 0.0, -2.0, 0.0, 0.0]), Some(-2.0));
+}
+
+#[test]
+fn test_make_monic() {
+    use fast_polynomial::algebra::{leading_coeff, make_monic};
+
+    let mut empty: [f64; 0] = [];
+    assert_eq!(make_monic(&mut empty), None);
+
+    let mut zero = [0.0, -0.0, 0.0];
+    assert_eq!(make_monic(&mut zero), None);
+    assert_eq!(zero.map(f64::to_bits), [0.0, -0.0, 0.0].map(f64::to_bits));
+
+    let mut c = [3.0f64, 1.5, -0.75, 0.0, 0.0];
+    let x = 0.7;
+    let before = poly(x, &c);
+
+    assert_eq!(make_monic(&mut c), Some(-0.75));
+    assert_eq!(c, [-4.0, -2.0, 1.0, 0.0, 0.0]);
+    assert_eq!(leading_coeff(&c), Some(1.0));
+    assert_feq!(1e-12, poly(x, &c) * -0.75, before);
+
+    let mut i = [6, 3, 3];
+    assert_eq!(make_monic(&mut i), Some(3));
+    assert_eq!(i, [2, 1, 1]);
+}
+
+#[test]
+fn test_poly_rem() {
+    use fast_polynomial::algebra::poly_rem;
+
+    // remainder of (x^3 - 6x^2 + 11x - 6) / (x^2 - 3x + 2) is zero
+    let mut r = [-6.0, 11.0, -6.0, 1.0];
+    poly_rem(&mut r, &[2.0, -3.0, 1.0]);
+    assert_eq!(r, [0.0; 4]);
+
+    // (x^2 + 1) / (x - 1) leaves 2
+    let mut r = [1.0, 0.0, 1.0];
+    poly_rem(&mut r, &[-1.0, 1.0]);
+    assert_eq!(r, [2.0, 0.0, 0.0]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_poly_gcd() {
+    use fast_polynomial::algebra::poly_gcd;
+
+    // (x - 1)(x - 2)(x - 3) and (x - 1)(x - 2)(x + 1)
+    let a = [-6.0, 11.0, -6.0, 1.0];
+    let b = [2.0, -1.0, -2.0, 1.0];
+
+    let mut out = [9.0; 4];
+    assert_eq!(poly_gcd(&a, &b, &mut out), 2);
+    assert_eq!(out, [2.0, -3.0, 1.0, 0.0]);
+
+    // coprime
+    let mut out = [9.0; 2];
+    assert_eq!(poly_gcd(&[1.0, 0.0, 1.0], &[-1.0, 1.0], &mut out), 0);
+    assert_eq!(out, [1.0, 0.0]);
+
+    // gcd with zero is the other polynomial, made monic
+    let mut out = [0.0; 3];
+    assert_eq!(poly_gcd(&[0.0], &[4.0, 2.0], &mut out), 1);
+    assert_eq!(out, [2.0, 1.0, 0.0]);
+
+    let mut out = [9.0; 1];
+    assert_eq!(poly_gcd::<f64>(&[], &[0.0], &mut out), 0);
+    assert_eq!(out, [0.0]);
+}
+
+#[cfg(feature = "deterministic")]
+#[test]
+fn test_deterministic() {
+    use fast_polynomial::{poly, poly_array};
+
+    // a * a - round(a * a) is only non-zero when fused
+    let a = 1.0 + 2f64.powi(-30);
+    assert_eq!(poly_array(a, &[-(a * a), a]), 0.0);
+
+    // matches a reference Estrin evaluation with separate roundings
+    let c = [
+        0.9066094402137101,
+        0.7030666449646632,
+        0.8062843184510005,
+        1.4354479997076703,
+    ];
+    let x = 0.7134f64;
+    let x2 = x * x;
+    let expected = x2 * (x * c[3] + c[2]) + (x * c[1] + c[0]);
+
+    assert_eq!(poly(x, &c).to_bits(), expected.to_bits());
+}
+
+#[test]
+fn test_complex_real_coeff() {
+    use fast_polynomial::complex::poly_complex_x_real_coeff;
+
+    fn complex_horner(x: (f64, f64), coeffs: &[f64]) -> (f64, f64) {
+        let mut acc = (0.0, 0.0);
+        for &c in coeffs.iter().rev() {
+            acc = (acc.0 * x.0 - acc.1 * x.1 + c, acc.0 * x.1 + acc.1 * x.0);
+        }
+        acc
+    }
+
+    let c = [
+        0.9066094402137101,
+        0.7030666449646632,
+        -0.8062843184510005,
+        1.4354479997076703,
+        -0.2,
+        0.05,
+    ];
+
+    for x in [
+        (0.0, 0.0),
+        (0.5, 0.0),
+        (0.0, 1.0),
+        (-0.3, 0.7),
+        (1.2, -0.9),
+        (0.8, 0.6),
+    ] {
+        for n in 0..=c.len() {
+            let (re, im) = poly_complex_x_real_coeff(x, &c[..n]);
+            let (er, ei) = complex_horner(x, &c[..n]);
+
+            assert_feq!(1e-13, er, re);
+            assert_feq!(1e-13, ei, im);
+        }
+    }
+}
+
+#[test]
+fn test_poly_scaled_input() {
+    use fast_polynomial::{accurate::poly_scaled_input, poly};
+
+    let c = [
+        0.9066094402137101,
+        -0.7030666449646632,
+        0.8062843184510005,
+        1.4354479997076703,
+    ];
+
+    // agrees with direct evaluation in range
+    for (m, e) in [(0.0, 0), (0.75, 0), (-0.6, 3), (1.5, -4), (3.0, 10)] {
+        let x = m * 2f64.powi(e);
+        let (rm, re) = poly_scaled_input(m, e, &c);
+
+        assert!(rm == 0.0 || (0.5..1.0).contains(&rm.abs()));
+        assert_feq!(1e-12, poly(x, &c), rm * 2f64.powi(re));
+    }
+
+    // far outside of the range of f64
+    let (m, e) = poly_scaled_input(1.0, 2000, &[1.0, 1.0, 1.0]);
+    assert_eq!((m, e), (0.5, 4001));
+
+    let (m, e) = poly_scaled_input(-0.75, -3000, &[0.0, 0.0, 0.0, 2.0]);
+    assert_eq!((m, e), (-0.84375, -9000));
+
+    // constant term dominates tiny x
+    let (m, e) = poly_scaled_input(1.0, -5000, &[3.0, 1.0]);
+    assert_eq!((m, e), (0.75, 2));
+
+    assert_eq!(poly_scaled_input(1.0, 0, &[]), (0.0, 0));
+}
+
+#[test]
+fn test_poly_array_leaves() {
+    use fast_polynomial::{poly, poly_array_leaves};
+
+    fn check<const N: usize, const L: usize>() {
+        let c: [f64; N] = core::array::from_fn(|i| 1.0 / (i + 1) as f64);
+        let mut leaves = [f64::NAN; L];
+
+        for x in [-1.5f64, -0.5, 0.0, 0.25, 1.1] {
+            poly_array_leaves(x, &c, &mut leaves);
+
+            for (i, &leaf) in leaves.iter().enumerate() {
+                let c1 = c.get(2 * i + 1).copied().unwrap_or(0.0);
+                assert_feq!(1e-15, c[2 * i] + x * c1, leaf);
+            }
+
+            let expected = poly(x, &c);
+            assert_feq!(
+                1e-14 * expected.abs().max(1.0),
+                expected,
+                poly(x * x, &leaves)
+            );
+        }
+    }
+
+    check::<0, 0>();
+    check::<1, 1>();
+    check::<2, 1>();
+    check::<7, 4>();
+    check::<16, 8>();
+    check::<33, 17>();
+}
+
+#[test]
+fn test_signed_zero() {
+    use fast_polynomial::{poly, poly_array, poly_f};
+
+    fn check<const N: usize>() {
+        // all coefficients negative zero, evaluated at non-negative x, must be negative zero,
+        // as every product and sum of negative zeros is also negative zero
+        let c = [-0.0f64; N];
+
+        for x in [0.0, 1.0, 0.5, 3.0] {
+            let expected = if N == 0 { 0.0f64 } else { -0.0 };
+
+            for y in [poly(x, &c), poly_array(x, &c), poly_f(x, N, |i| c[i])] {
+                assert_eq!(y.to_bits(), expected.to_bits(), "N = {N}, x = {x}");
+            }
+        }
+
+        // and every entry point agrees exactly for mixed signed zeros at any signed x
+        let c: [f64; N] = core::array::from_fn(|i| if i % 3 == 0 { -0.0 } else { 0.0 });
+
+        for x in [0.0, -0.0, 1.0, -1.0, 2.0, -2.0] {
+            let y = poly(x, &c);
+
+            assert_eq!(y, 0.0);
+            assert_eq!(y.to_bits(), poly_array(x, &c).to_bits(), "N = {N}, x = {x}");
+            assert_eq!(
+                y.to_bits(),
+                poly_f(x, N, |i| c[i]).to_bits(),
+                "N = {N}, x = {x}"
+            );
+        }
+    }
+
+    macro_rules! check {
+        ($($n:literal)*) => {$(check::<$n>();)*};
+    }
+
+    check!(0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 31 32 33 47 48 63 64 65 100 128 129);
+}
+
+#[test]
+fn test_poly_prefetch() {
+    use fast_polynomial::{poly, poly_prefetch};
+
+    let mut state = 0x853C49E6748FEA9B;
+    let c: Vec<f64> = (0..5000).map(|_| xorshift(&mut state) - 0.5).collect();
+    let c32: Vec<f32> = c.iter().map(|&c| c as f32).collect();
+
+    for n in [0, 1, 7, 16, 17, 100, 1000, 5000] {
+        for x in [-1.0, -0.3, 0.0, 0.7, 1.0] {
+            assert_eq!(
+                poly(x, &c[..n]).to_bits(),
+                poly_prefetch(x, &c[..n]).to_bits()
+            );
+            assert_eq!(
+                poly(x as f32, &c32[..n]).to_bits(),
+                poly_prefetch(x as f32, &c32[..n]).to_bits()
+            );
+        }
+    }
+}
+
+#[test]
+fn test_poly_strided() {
+    use fast_polynomial::{poly, poly_strided};
+
+    let m: Vec<f64> = (0..200).map(|i| 1.0 / (i + 1) as f64).collect();
+
+    for stride in 1..6 {
+        for count in 0..=(200 - 1) / stride + 1 {
+            let c: Vec<f64> = m.iter().step_by(stride).take(count).copied().collect();
+            assert_eq!(poly(0.7, &c), poly_strided(0.7, &m, stride, count));
+        }
+    }
+
+    // stride of zero repeats the first coefficient
+    assert_eq!(poly_strided(0.5, &[2.0], 0, 3), poly(0.5, &[2.0, 2.0, 2.0]));
+    assert_eq!(poly_strided(0.5, &[0.0; 0], 7, 0), 0.0);
+}
+
+#[test]
+#[should_panic]
+fn test_poly_strided_out_of_bounds() {
+    fast_polynomial::poly_strided(0.5, &[1.0; 10], 3, 5);
+}
+
+#[test]
+fn test_poly_macro() {
+    use fast_polynomial::{poly, poly_array};
+
+    for x in [-1.5f64, -0.5, 0.0, 0.3, 2.0] {
+        assert_eq!(poly!(x;), 0.0);
+        assert_eq!(poly!(x; 1.5), 1.5);
+        assert_eq!(
+            poly!(x; 1.0, -0.5, 0.04166, -0.00138,),
+            poly(x, &[1.0, -0.5, 0.04166, -0.00138])
+        );
+
+        #[rustfmt::skip]
+        let y = poly!(x; 0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0, 1.1, 1.2, 1.3, 1.4, 1.5, 1.6, 1.7);
+        let c: [f64; 17] = core::array::from_fn(|i| (i + 1) as f64 / 10.0);
+        assert_eq!(y.to_bits(), poly_array(x, &c).to_bits());
+    }
+
+    let x = 0.25f32;
+    assert_eq!(poly!(x * 2.0; 1.0, 2.0), 2.0f32);
+}
+
+#[test]
+fn test_differentiate() {
+    use fast_polynomial::{algebra::differentiate, poly};
+
+    let c = [0.5, -1.0, 2.0, 0.25, 3.0];
+    let mut d = [0.0; 4];
+    differentiate(&c, &mut d);
+
+    assert_eq!(d, [-1.0, 4.0, 0.75, 12.0]);
+
+    // central difference of the original polynomial
+    let h = 1e-5;
+    for x in [-1.0f64, 0.0, 0.3, 2.0] {
+        let fd = (poly(x + h, &c) - poly(x - h, &c)) / (2.0 * h);
+        assert_feq!(1e-6 * fd.abs().max(1.0), fd, poly(x, &d));
+    }
+
+    let mut d = [0i64; 3];
+    differentiate(&[7, 1, 1, 1], &mut d);
+    assert_eq!(d, [1, 2, 3]);
+
+    differentiate::<f32>(&[1.0], &mut []);
+    differentiate::<f32>(&[], &mut []);
+}
+
+#[test]
+fn test_array_wrap_gather_scatter() {
+    use fast_polynomial::{many_xs::ArrayWrap, poly_array};
+
+    let src: Vec<f32> = (0..32).map(|i| i as f32 / 32.0).collect();
+    let indices = [31, 0, 7, 7, 12, 3, 25, 18];
+
+    let xs = ArrayWrap::gather(&src, &indices);
+    assert_eq!(xs.0, indices.map(|i| src[i]));
+
+    let c = [1.0, 0.3, 0.4, 1.6].map(ArrayWrap::splat);
+    let ys = poly_array(xs, &c);
+
+    let mut dst = vec![0.0f32; 32];
+    ys.scatter(&mut dst, &indices);
+
+    for (i, &y) in dst.iter().enumerate() {
+        if indices.contains(&i) {
+            assert_eq!(y, poly_array(src[i], &[1.0, 0.3, 0.4, 1.6]));
+        } else {
+            assert_eq!(y, 0.0);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_array_wrap_gather_out_of_bounds() {
+    fast_polynomial::many_xs::ArrayWrap::gather(&[1.0f32; 4], &[0, 4]);
+}
+
+#[test]
+fn test_poly_fold() {
+    use fast_polynomial::poly_fold;
+
+    let c = [
+        0.9066094402137101,
+        0.7030666449646632,
+        0.8062843184510005,
+        1.4354479997076703,
+    ];
+
+    // standard arithmetic is Horner's method
+    for x in [-1.5f64, 0.0, 0.7] {
+        let y = poly_fold(x, &c, |a, b| a * b, |a, b| a + b).unwrap();
+        assert_feq!(1e-14, horners_method(x, &c), y);
+    }
+
+    // min-plus
+    let c = [5.0, 1.0, 0.5, 4.0];
+    for x in [-2.0f64, 0.0, 1.0, 3.0] {
+        let y = poly_fold(x, &c, |a, b| a + b, f64::min).unwrap();
+        let expected = c
+            .iter()
+            .enumerate()
+            .map(|(k, &c)| c + k as f64 * x)
+            .fold(f64::INFINITY, f64::min);
+        assert_eq!(y, expected);
+    }
+
+    // max-plus over integers
+    assert_eq!(
+        poly_fold(3i64, &[0, 2, -10], |a, b| a + b, i64::max),
+        Some(5)
+    );
+
+    assert_eq!(poly_fold(1.0f64, &[], |a, b| a * b, |a, b| a + b), None);
+}
+
+#[test]
+fn test_poly_auto() {
+    use fast_polynomial::{accurate::poly_stable, many_xs::ArrayWrap, poly, poly_auto};
+
+    let c: Vec<f64> = (0..40).map(|i| 1.0 / (i + 1) as f64).collect();
+
+    for n in 0..40 {
+        assert_eq!(poly_auto(0.7, &c[..n]), poly(0.7, &c[..n]));
+
+        let cw: Vec<ArrayWrap<16, f64>> = c[..n].iter().map(|&c| ArrayWrap::splat(c)).collect();
+        let x = ArrayWrap::splat(0.7);
+
+        let expected = if n >= 16 {
+            poly_stable(x, &cw)
+        } else {
+            poly(x, &cw)
+        };
+        assert_eq!(poly_auto(x, &cw), expected);
+    }
+}
+
+#[test]
+fn test_rational_array_checked() {
+    use fast_polynomial::{
+        many_xs::{rational_array_checked, ArrayWrap},
+        poly_array,
+    };
+
+    // (1 + x²) / ((x - 0.5)(x + 0.25)) = (1 + x²) / (x² - 0.25x - 0.125)
+    let n = [1.0f64, 0.0, 1.0];
+    let d = [-0.125f64, -0.25, 1.0];
+
+    let xs = ArrayWrap([0.5, -0.25, 0.0, 0.75, -0.5, 0.5, 0.1, 0.9]);
+
+    let (ys, zero) = rational_array_checked(xs, &n.map(ArrayWrap::splat), &d.map(ArrayWrap::splat));
+
+    assert_eq!(zero, [true, true, false, false, false, true, false, false]);
+
+    for ((&x, &y), &zero) in xs.0.iter().zip(&ys.0).zip(&zero) {
+        if zero {
+            assert!(y.is_infinite());
+        } else {
+            assert_feq!(1e-15, y, poly_array(x, &n) / poly_array(x, &d));
+        }
+    }
+}
+
+#[test]
+fn test_poly_vec() {
+    use fast_polynomial::{poly, poly_vec};
+
+    let mut state = 0x9e3779b97f4a7c15;
+    let c: Vec<f64> = (0..40).map(|_| xorshift(&mut state)).collect();
+
+    for n in 0..=c.len() {
+        for x in [-1.5, -0.5, 0.0, 0.25, 0.9, 2.0] {
+            assert_eq!(poly_vec(x, &c[..n]), poly(x, &c[..n]));
+        }
+    }
+}
+
+#[test]
+fn test_poly_array_no_contract() {
+    use fast_polynomial::{poly_array, poly_array_no_contract};
+
+    let mut state = 0x2545f4914f6cdd1d;
+    let c: [f64; 24] = core::array::from_fn(|_| xorshift(&mut state) - 0.5);
+
+    for _ in 0..100 {
+        let x = xorshift(&mut state) * 2.0 - 1.0;
+
+        let [c0, c1, c2, c3] = [c[0], c[1], c[2], c[3]];
+        assert_eq!(
+            poly_array_no_contract(x, &[c0, c1, c2, c3]),
+            (x * c3 + c2) * (x * x) + (x * c1 + c0)
+        );
+
+        assert_feq!(1e-13, poly_array_no_contract(x, &c), horners_method(x, &c));
+
+        if cfg!(any(feature = "deterministic", not(target_feature = "fma"))) {
+            assert_eq!(poly_array_no_contract(x, &c), poly_array(x, &c));
+        }
+    }
+}
+
+#[test]
+#[cfg(any(feature = "std", feature = "libm"))]
+fn test_eval_at_roots_of_unity() {
+    use fast_polynomial::complex::{eval_at_roots_of_unity, poly_complex};
+    use std::f64::consts::TAU;
+
+    let mut state = 0x853c49e6748fea9b;
+    let c: Vec<(f64, f64)> = (0..13)
+        .map(|_| (xorshift(&mut state) - 0.5, xorshift(&mut state) - 0.5))
+        .collect();
+
+    // (a + bi)(c + di) = (1 + 2i)(3 - i) = 5 + 5i
+    assert_eq!(
+        poly_complex((3.0, -1.0), &[(0.0, 0.0), (1.0, 2.0)]),
+        (5.0, 5.0)
+    );
+    assert_eq!(poly_complex((3.0, -1.0), &[]), (0.0, 0.0));
+
+    for n in [1, 2, 3, 8, 13, 16, 20] {
+        let mut out = vec![(f64::NAN, f64::NAN); n];
+        eval_at_roots_of_unity(&c, &mut out);
+
+        for (k, &(re, im)) in out.iter().enumerate() {
+            // direct sum of c_j e^(2πi jk/n), reducing jk mod n for accurate angles
+            let (mut er, mut ei) = (0.0, 0.0);
+            for (j, &(cr, ci)) in c.iter().enumerate() {
+                let (s, co) = (TAU * ((j * k) % n) as f64 / n as f64).sin_cos();
+                er += cr * co - ci * s;
+                ei += cr * s + ci * co;
+            }
+
+            assert_feq!(1e-13, re, er);
+            assert_feq!(1e-13, im, ei);
+        }
+    }
+
+    eval_at_roots_of_unity::<f64>(&c, &mut []);
+}
+
+#[test]
+fn test_poly_quantized() {
+    use fast_polynomial::poly_quantized;
+
+    fn reference(x: i16, coeffs: &[i8]) -> i128 {
+        coeffs
+            .iter()
+            .rev()
+            .fold(0, |sum, &c| sum * x as i128 + c as i128)
+    }
+
+    let c = [-128, 127, -3, 42, 0, -77];
+
+    for x in [i16::MIN, -1000, -2, -1, 0, 1, 3, 181, i16::MAX] {
+        for n in 0..=c.len() {
+            // wrapping, so exact modulo 2^32 even when the result does not fit
+            assert_eq!(poly_quantized(x, &c[..n]), reference(x, &c[..n]) as i32);
+        }
+    }
+
+    assert_eq!(poly_quantized(1000, &[]), 0);
+    assert_eq!(poly_quantized(-1000, &[1, 0, 1]), 1_000_001);
+}
+
+#[test]
+fn test_poly_widen_i32() {
+    use fast_polynomial::{poly_widen_i32, poly_widen_i32_checked, PolyError};
+
+    // returns the result, and whether every intermediate result fits in an i64
+    fn reference(x: i64, coeffs: &[i32]) -> (i128, bool) {
+        coeffs.iter().rev().fold((0, true), |(sum, fits), &c| {
+            let product = sum * x as i128;
+            let sum = product + c as i128;
+            let fits = fits && i64::try_from(product).is_ok() && i64::try_from(sum).is_ok();
+            (sum, fits)
+        })
+    }
+
+    let c = [i32::MIN, i32::MAX, -3, 42, 0, -77];
+
+    for x in [-65536, -1000, -2, -1, 0, 1, 3, 181, 40000] {
+        for n in 0..=c.len() {
+            let (expected, fits) = reference(x, &c[..n]);
+
+            // wrapping, so exact modulo 2^64 even when the result does not fit
+            assert_eq!(poly_widen_i32(x, &c[..n]), expected as i64);
+            assert_eq!(
+                poly_widen_i32_checked(x, &c[..n]),
+                if fits {
+                    Ok(expected as i64)
+                } else {
+                    Err(PolyError::Overflow)
+                }
+            );
+        }
+    }
+
+    for x in [i64::MIN, -(1 << 40), 1 << 33, i64::MAX] {
+        let c = [1, -2, 3];
+        let expected = 1i64
+            .wrapping_add(x.wrapping_mul(-2))
+            .wrapping_add(x.wrapping_mul(x).wrapping_mul(3));
+
+        assert_eq!(poly_widen_i32(x, &c), expected);
+        assert_eq!(poly_widen_i32_checked(x, &c), Err(PolyError::Overflow));
+    }
+}
+
+#[test]
+fn test_poly_array_scaled() {
+    use fast_polynomial::{accurate::poly_array_scaled, poly_array};
+
+    // p(x) = 2^-1030 (1 - x)^3, which is entirely within the subnormal range for x in [0, 1]
+    let s = f64::MIN_POSITIVE / 256.0; // 2^-1030
+    let c = [s, -3.0 * s, 3.0 * s, -s];
+
+    for i in 0..=16 {
+        let x = i as f64 / 16.0;
+
+        let y = poly_array(x, &c);
+        let scaled = poly_array_scaled(x, &c, 1100);
+
+        // gradual underflow on the host loses most of the precision, or all of it under FTZ
+        assert!(y == 0.0 || y.is_subnormal());
+
+        let expected = (1.0 - x).powi(3) * 2f64.powi(70);
+        assert_feq!(1e-15 * 2f64.powi(70), scaled, expected);
+
+        // scaling by a power of two is exact when no term is subnormal
+        assert_eq!(
+            poly_array_scaled(x, &c, 1100),
+            poly_array_scaled(x, &c, 1060) * 2f64.powi(40)
+        );
+    }
+}
+
+#[test]
+fn test_display() {
+    use fast_polynomial::algebra::display;
+
+    assert_eq!(display::<f64>(&[]).to_string(), "0");
+    assert_eq!(display(&[0.0, -0.0]).to_string(), "0");
+    assert_eq!(display(&[1.0]).to_string(), "1");
+    assert_eq!(display(&[-1.0]).to_string(), "-1");
+    assert_eq!(display(&[0.0, 1.0]).to_string(), "x");
+    assert_eq!(display(&[0.0, -1.0]).to_string(), "-x");
+    assert_eq!(display(&[-1, 2, 3]).to_string(), "3x^2 + 2x - 1");
+    assert_eq!(display(&[1, 0, 0, -1]).to_string(), "-x^3 + 1");
+    assert_eq!(display(&[0.5, -1.0, 0.0, 0.0, 0.0]).to_string(), "-x + 0.5");
+
+    let mut c = [0i64; 124];
+    c[123] = -7;
+    c[10] = 1;
+    assert_eq!(format!("{:#}", display(&c)), "-7x¹²³ + x¹⁰");
+    assert_eq!(format!("{}", display(&c)), "-7x^123 + x^10");
+
+    assert_eq!(
+        format!("{:.2}", display(&[0.125, 1.0 / 3.0])),
+        "0.33x + 0.12"
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_memo_poly() {
+    use fast_polynomial::{memo::MemoPoly, poly};
+
+    let c = [0.9, -0.3, 0.4, 1.6, 0.25];
+    let mut p = MemoPoly::new(c.to_vec(), 3);
+
+    assert!(p.is_empty());
+    assert_eq!(p.coeffs(), &c);
+
+    for x in [0.5, 0.5, -0.0, 0.0, 1.5, 0.5] {
+        assert_eq!(p.eval(x).to_bits(), poly(x, &c).to_bits());
+    }
+
+    // 0.5 was evicted by 1.5, then reinserted evicting -0.0
+    assert_eq!(p.len(), 3);
+
+    assert!(p.eval(f64::NAN).is_nan());
+    assert_eq!(p.len(), 3);
+
+    p.clear();
+    assert!(p.is_empty());
+
+    let mut uncached = MemoPoly::new(c.to_vec(), 0);
+    assert_eq!(uncached.eval(2.0), poly(2.0, &c));
+    assert!(uncached.is_empty());
+}
+
+#[test]
+fn test_poly_array_dd_acc() {
+    use fast_polynomial::{
+        accurate::{poly_array_dd_acc, DoubleDouble},
+        poly_array,
+    };
+
+    let reference = |x: f64, c: &[f64; 8]| {
+        poly_array(DoubleDouble::from(x), &c.map(DoubleDouble::from)).to_f64()
+    };
+
+    // (x - 1)^7 expanded
+    let c = [-1.0, 7.0, -21.0, 35.0, -35.0, 21.0, -7.0, 1.0];
+
+    // condition numbers up to 10^14, far beyond what f64 Horner's method can handle
+    for i in 2..100 {
+        let x = 1.0 + i as f64 * 1e-2;
+        let expected = reference(x, &c);
+
+        assert!((poly_array_dd_acc(x, &c) - expected).abs() <= 2.0 * f64::EPSILON * expected.abs());
+    }
+
+    let mut state = 0xdeadbeefcafef00d;
+    for _ in 0..1000 {
+        let c: [f64; 8] = core::array::from_fn(|_| xorshift(&mut state) * 2.0 - 1.0);
+        let x = xorshift(&mut state) * 4.0 - 2.0;
+        let expected = reference(x, &c);
+
+        assert!((poly_array_dd_acc(x, &c) - expected).abs() <= f64::EPSILON * expected.abs());
+    }
+
+    assert_eq!(poly_array_dd_acc(2.0, &[]), 0.0);
+    assert_eq!(poly_array_dd_acc(2.0, &[3.0]), 3.0);
+}
+
+#[test]
+fn test_poly_permuted() {
+    use fast_polynomial::{poly, poly_permuted};
+
+    let mut state = 0x0123456789abcdef;
+    let c: Vec<f64> = (0..64).map(|_| xorshift(&mut state)).collect();
+
+    for bits in 0..=6 {
+        let n = 1usize << bits;
+
+        // bit-reversal permutation of 0..n
+        let perm: Vec<usize> = (0..n)
+            .map(|i| {
+                i.reverse_bits()
+                    .checked_shr(usize::BITS - bits)
+                    .unwrap_or(0)
+            })
+            .collect();
+
+        let stored: Vec<f64> = perm.iter().map(|&i| c[i]).collect();
+
+        for x in [-1.5, -0.5, 0.0, 0.25, 0.9] {
+            assert_eq!(poly_permuted(x, &stored, &perm), poly(x, &c[..n]));
+        }
+    }
+
+    // repeated and omitted indices
+    assert_eq!(
+        poly_permuted(2.0, &[1.0, 2.0, 3.0], &[2, 2, 0]),
+        3.0 + 6.0 + 4.0
+    );
+    assert_eq!(poly_permuted(2.0, &[1.0], &[]), 0.0);
+}
+
+#[test]
+#[should_panic]
+fn test_poly_permuted_out_of_bounds() {
+    fast_polynomial::poly_permuted(0.5, &[1.0, 2.0], &[0, 2]);
+}
+
+#[test]
+fn test_poly_array_with_powers() {
+    use fast_polynomial::{poly_array, poly_array_powers, poly_array_with_powers, Powers};
+
+    let mut state = 0x94d049bb133111eb;
+    let c: [f64; 70] = core::array::from_fn(|_| xorshift(&mut state) - 0.5);
+
+    macro_rules! check {
+        ($x:expr; $($n:literal)*) => {$({
+            let a: &[f64; $n] = c[..$n].try_into().unwrap();
+            let b: &[f64; $n] = c[70 - $n..].try_into().unwrap();
+
+            let (y, powers) = poly_array_with_powers($x, a);
+
+            assert_eq!(y.to_bits(), poly_array($x, a).to_bits());
+            assert_eq!(poly_array_powers(&powers, b).to_bits(), poly_array($x, b).to_bits());
+        })*};
+    }
+
+    for x in [-1.1, -0.5, -0.0, 0.3, 0.99] {
+        check!(x; 0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 20 31 32 33 47 64 65 70);
+
+        let p = Powers::new(x);
+        assert_eq!(p.x8 * p.x8, p.x16);
+    }
+}
+
+#[test]
+fn test_poly_array_sum() {
+    use fast_polynomial::{many_xs::poly_array_sum, poly};
+
+    let mut state = 0xbf58476d1ce4e5b9;
+    let xs: Vec<f64> = (0..103).map(|_| xorshift(&mut state) * 2.0 - 1.0).collect();
+    let c: Vec<f64> = (0..20).map(|_| xorshift(&mut state) - 0.5).collect();
+
+    for len in [0, 1, 7, 8, 9, 64, 103] {
+        for n in [0, 1, 4, 13, 20] {
+            let expected: f64 = xs[..len].iter().map(|&x| poly(x, &c[..n])).sum();
+
+            assert_feq!(1e-12, poly_array_sum::<1, _>(&xs[..len], &c[..n]), expected);
+            assert_feq!(1e-12, poly_array_sum::<4, _>(&xs[..len], &c[..n]), expected);
+            assert_feq!(1e-12, poly_array_sum::<8, _>(&xs[..len], &c[..n]), expected);
+        }
+    }
+}
+
+#[test]
+fn test_poly_array_mixed() {
+    use fast_polynomial::{poly_array, poly_array_mixed};
+
+    let mut state = 0x5851f42d4c957f2d;
+    let c: [f32; 12] = core::array::from_fn(|_| xorshift(&mut state) as f32 - 0.5);
+
+    for _ in 0..100 {
+        let x = xorshift(&mut state) as f32 * 2.0 - 1.0;
+
+        let y: f64 = poly_array_mixed(x, &c);
+        assert_eq!(y, poly_array(x as f64, &c.map(f64::from)));
+
+        let y: f32 = poly_array_mixed(x, &c);
+        assert_eq!(y, poly_array(x, &c));
+    }
+}
+
+/// Randomized differential tests of the optimized evaluation routines against a naive Horner's method reference.
+///
+/// Both the optimized routines and the reference are computed in `f64`, so they will differ by rounding error
+/// that scales with the condition of the evaluation. For a polynomial with `n` coefficients, results must agree
+/// to within `4n` ULPs of `Σ|c_k x^k|`, which is a small multiple of the standard error bound for either scheme.
+/// Inputs span magnitudes from `1e-3` to `1e3`, of both signs, to exercise the reciprocal path of rational evaluation.
+mod differential {
+    use super::{horners_method, xorshift};
+    use fast_polynomial::{poly, poly_array, rational, rational_array};
+
+    const SAMPLES: usize = 200;
+
+    /// `Σ|c_k x^k|`
+    fn abs_sum(x: f64, c: &[f64]) -> f64 {
+        c.iter().rev().fold(0.0, |sum, &c| sum * x.abs() + c.abs())
+    }
+
+    fn tolerance(x: f64, c: &[f64]) -> f64 {
+        4.0 * c.len() as f64 * f64::EPSILON * abs_sum(x, c)
+    }
+
+    fn random_x(state: &mut u64) -> f64 {
+        let magnitude = 10f64.powf(xorshift(state) * 6.0 - 3.0);
+
+        if xorshift(state) < 0.5 {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+
+    #[test]
+    fn test_differential_poly() {
+        macro_rules! check_lengths {
+            ($($n:literal)*) => {$({
+                let mut state = 0xD1B5_4A32_D192_ED03_u64 ^ $n;
+
+                for _ in 0..SAMPLES {
+                    let c: [f64; $n] = core::array::from_fn(|_| xorshift(&mut state) * 2.0 - 1.0);
+                    let x = random_x(&mut state);
+
+                    let expected = horners_method(x, &c);
+                    let tolerance = tolerance(x, &c);
+
+                    for (name, y) in [("poly", poly(x, &c)), ("poly_array", poly_array(x, &c))] {
+                        assert!(
+                            (y - expected).abs() <= tolerance,
+                            "{name}: N = {}, x = {x}, {y} != {expected}", $n
+                        );
+                    }
+                }
+            })*};
+        }
+
+        check_lengths!(
+             0  1  2  3  4  5  6  7  8  9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32
+            33 34 35 36 37 38 39 40 41 42 43 44 45 46 47 48 49 50 51 52 53 54 55 56 57 58 59 60 61 62 63 64
+        );
+    }
+
+    #[test]
+    fn test_differential_rational() {
+        macro_rules! check_degrees {
+            ($(($p:literal, $q:literal))*) => {$({
+                let mut state = 0x8CB9_2BA7_2F3D_8DD7_u64 ^ ($p * 67 + $q);
+
+                for _ in 0..SAMPLES {
+                    let n: [f64; $p] = core::array::from_fn(|_| xorshift(&mut state) * 2.0 - 1.0);
+                    let d: [f64; $q] = core::array::from_fn(|_| xorshift(&mut state) * 2.0 - 1.0);
+                    let x = random_x(&mut state);
+
+                    let (pn, pd) = (horners_method(x, &n), horners_method(x, &d));
+                    let expected = pn / pd;
+
+                    if !expected.is_finite() {
+                        continue;
+                    }
+
+                    // first-order propagation of the errors of the numerator and denominator through the division,
+                    // with an extra ULP per coefficient for the rounding of 1/x in the reciprocal path
+                    let tolerance = (tolerance(x, &n) + expected.abs() * tolerance(x, &d)) / pd.abs()
+                        + 2.0 * ($p + $q) as f64 * f64::EPSILON * expected.abs();
+
+                    for (name, y) in [("rational", rational(x, &n, &d)), ("rational_array", rational_array(x, &n, &d))] {
+                        assert!(
+                            (y - expected).abs() <= tolerance,
+                            "{name}: P = {}, Q = {}, x = {x}, {y} != {expected}", $p, $q
+                        );
+                    }
+                }
+            })*};
+        }
+
+        check_degrees!(
+            (1, 1)(1, 2)(2, 1)(2, 2)(3, 3)(3, 5)(5, 3)(4, 4)(6, 6)(7, 2)(2, 7)(8, 8)(9, 12)(12, 9)(
+                16, 16
+            )(17, 17)(20, 24)(31, 31)(32, 32)(33, 33)
+        );
+    }
+}
+
+#[test]
+fn test_poly_symmetric() {
+    use fast_polynomial::poly_symmetric;
+
+    let mut state = 0x6a09e667f3bcc909;
+    let half: Vec<f64> = (0..20).map(|_| xorshift(&mut state) - 0.5).collect();
+
+    for h in 0..=half.len() {
+        for odd in [false, true] {
+            if h == 0 && odd {
+                continue;
+            }
+
+            // expand to the full palindromic coefficients
+            let mut full = half[..h].to_vec();
+            full.extend(half[..h - odd as usize].iter().rev());
+
+            for x in [-1.5, -0.7, 0.0, 0.3, 0.9, 1.2] {
+                let expected = horners_method(x, &full);
+                assert_feq!(
+                    1e-12 * (1.0 + expected.abs()),
+                    poly_symmetric(x, &half[..h], odd),
+                    expected
+                );
+            }
+        }
+    }
+
+    assert_eq!(poly_symmetric(2.0, &[], false), 0.0);
+    assert_eq!(poly_symmetric(2.0, &[3.0], true), 3.0);
+    assert_eq!(poly_symmetric(2.0, &[3.0], false), 9.0);
+}
+
+#[test]
+fn test_poly_array_batch() {
+    use fast_polynomial::{many_xs::poly_array_batch, poly_array};
+
+    let mut state = 0xbb67ae8584caa73b;
+    let xs: Vec<f64> = (0..37).map(|_| xorshift(&mut state) * 2.0 - 1.0).collect();
+    let c: [f64; 11] = core::array::from_fn(|_| xorshift(&mut state) - 0.5);
+
+    for len in [0, 1, 3, 4, 8, 16, 37] {
+        let expected: Vec<f64> = xs[..len].iter().map(|&x| poly_array(x, &c)).collect();
+
+        let mut out = vec![f64::NAN; len];
+        poly_array_batch::<1, _, 11>(&xs[..len], &c, &mut out);
+        assert_eq!(out, expected);
+
+        let mut out = vec![f64::NAN; len];
+        poly_array_batch::<4, _, 11>(&xs[..len], &c, &mut out);
+        assert_eq!(out, expected);
+
+        let mut out = vec![f64::NAN; len];
+        poly_array_batch::<16, _, 11>(&xs[..len], &c, &mut out);
+        assert_eq!(out, expected);
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_poly_array_batch_length_mismatch() {
+    fast_polynomial::many_xs::poly_array_batch::<4, _, 2>(&[1.0; 8], &[1.0, 2.0], &mut [0.0; 7]);
+}
+
+#[test]
+fn test_term() {
+    use fast_polynomial::{powi, term};
+
+    for k in 0..70 {
+        for x in [-1.5, -0.5, 0.0, 0.75, 1.1, 2.0] {
+            assert_eq!(term(0.3, x, k), 0.3 * powi(x, k));
+        }
+
+        assert_eq!(term(-2i64, 3, k.min(30)), -2 * 3i64.pow(k.min(30)));
+    }
+
+    assert_eq!(term(0.3, f64::INFINITY, 0), 0.3);
+    assert_eq!(term(0.0, 2.0, 3), 0.0);
+}
+
+#[test]
+fn test_evaluate() {
+    use fast_polynomial::{poly, poly_array, Evaluate};
+
+    fn eval_all<P: Evaluate<f64> + ?Sized>(p: &P, xs: &[f64]) -> Vec<f64> {
+        xs.iter().map(|&x| p.eval(x)).collect()
+    }
+
+    let xs = [-1.5, -0.5, 0.0, 0.25, 0.9, 2.0];
+
+    let mut state = 0xa54ff53a5f1d36f1;
+    let c: [f64; 20] = core::array::from_fn(|_| xorshift(&mut state) - 0.5);
+
+    let expected_array: Vec<f64> = xs.iter().map(|&x| poly_array(x, &c)).collect();
+    let expected_slice: Vec<f64> = xs.iter().map(|&x| poly(x, &c)).collect();
+
+    assert_eq!(eval_all(&c, &xs), expected_array);
+    assert_eq!(eval_all(&&c, &xs), expected_array);
+    assert_eq!(eval_all(&c[..], &xs), expected_slice);
+    assert_eq!(eval_all(&&c[..], &xs), expected_slice);
+
+    #[cfg(feature = "alloc")]
+    assert_eq!(eval_all(&c.to_vec(), &xs), expected_slice);
+
+    assert_eq!(c.eval(0.5), poly_array(0.5, &c));
+    assert_eq!([1i64, 2, 3].eval(10), 321);
+    assert_eq!(Evaluate::<f64>::eval(&[0.0; 0], 2.0), 0.0);
+}
+
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+#[test]
+fn test_downcast_poly() {
+    use fast_polynomial::{poly, poly_coeffs, DowncastPoly, Evaluate};
+
+    let mut state = 0x510e527fade682d1;
+    let loaded: Vec<f64> = (0..25).map(|_| xorshift(&mut state) - 0.5).collect();
+    let converted: Vec<f32> = loaded.iter().map(|&c| c as f32).collect();
+
+    let p = DowncastPoly::new(&loaded);
+    assert_eq!(p.coeffs(), &converted[..]);
+
+    for x in [-1.5f32, -0.5, 0.0, 0.25, 0.9] {
+        assert_eq!(p.eval(x), poly(x, &converted));
+        assert_eq!(poly_coeffs(x, &p), poly(x, &converted));
+        assert_eq!(Evaluate::eval(&p, x), poly(x, &converted));
+
+        assert_feq!(
+            1e-5 * (1.0 + p.eval(x).abs() as f64),
+            p.eval(x) as f64,
+            poly(x as f64, &loaded)
+        );
+    }
+
+    assert_eq!(DowncastPoly::new(&[]).eval(2.0), 0.0);
+}
+
+#[test]
+fn test_poly_error() {
+    use fast_polynomial::{
+        poly_quantized, poly_quantized_checked, try_poly_array, try_rational, PolyError,
+    };
+
+    let c = [0.9, -0.3, 0.4, 1.6, 0.25];
+
+    assert_eq!(try_poly_array::<f64, 5>(0.5, &c), Ok(poly(0.5, &c)));
+    assert_eq!(
+        try_poly_array::<f64, 4>(0.5, &c),
+        Err(PolyError::LengthMismatch {
+            expected: 4,
+            found: 5
+        })
+    );
+    assert_eq!(try_poly_array::<f64, 0>(0.5, &[]), Ok(0.0));
+
+    assert_eq!(
+        try_rational(2.0, &c, &c[..2]),
+        Ok(rational(2.0, &c, &c[..2]))
+    );
+    assert_eq!(try_rational(2.0, &[], &c[..2]), Ok(0.0));
+    assert_eq!(try_rational(2.0, &c, &[]), Err(PolyError::EmptyDenominator));
+
+    #[cfg(any(feature = "std", feature = "libm"))]
+    {
+        use fast_polynomial::poly_finite;
+
+        assert_eq!(poly_finite(0.5, &c), Ok(poly(0.5, &c)));
+        assert_eq!(poly_finite(f64::INFINITY, &c), Err(PolyError::NonFinite));
+        assert_eq!(poly_finite(0.5, &[f64::NAN]), Err(PolyError::NonFinite));
+    }
+
+    let q = [-128, 127, -3, 42];
+    for x in [-300, -2, 0, 1, 181, 290] {
+        assert_eq!(poly_quantized_checked(x, &q), Ok(poly_quantized(x, &q)));
+    }
+    assert_eq!(
+        poly_quantized_checked(i16::MIN, &q),
+        Err(PolyError::Overflow)
+    );
+
+    assert_eq!(
+        PolyError::LengthMismatch {
+            expected: 4,
+            found: 5
+        }
+        .to_string(),
+        "expected 4 coefficients, found 5"
+    );
+
+    let _: &dyn std::error::Error = &PolyError::Overflow;
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_poly_sparse_map() {
+    use fast_polynomial::poly_sparse_map;
+    use std::collections::BTreeMap;
+
+    assert_eq!(poly_sparse_map(2.0f64, &BTreeMap::new()), 0.0);
+    assert_eq!(poly_sparse_map(2.0f64, &BTreeMap::from([(5, 3.0)])), 96.0);
+    assert_eq!(
+        poly_sparse_map(3i64, &BTreeMap::from([(0, 1), (2, 2), (3, -1), (9, 1)])),
+        1 + 18 - 27 + 19683
+    );
+
+    let mut state = 0x2545_F491_4F6C_DD1D;
+    let mut terms = BTreeMap::new();
+    for k in [0, 1, 4, 5, 11, 12, 13, 30] {
+        terms.insert(k, xorshift(&mut state));
+    }
+
+    let mut dense = [0.0; 31];
+    for (&k, &c) in &terms {
+        dense[k as usize] = c;
+    }
+
+    for i in 0..100 {
+        let x = i as f64 / 50.0 - 1.0;
+        assert_feq!(1e-12, poly_sparse_map(x, &terms), horners_method(x, &dense));
+    }
+}
+
+#[test]
+fn test_rational_array_extremes() {
+    use fast_polynomial::rational_array;
+
+    let mut state = 0x9E37_79B9_7F4A_7C15;
+    let n: [f64; 5] = core::array::from_fn(|_| xorshift(&mut state));
+    let d: [f64; 4] = core::array::from_fn(|_| xorshift(&mut state));
+
+    for x in [-3.0, -1.0, -0.5, 0.0, 0.25, 1.0, 1.0 + f64::EPSILON, 7.0] {
+        let a = rational_array(x, &n, &d);
+        let b = poly(x, &n) / poly(x, &d);
+        assert!(
+            a == b || (a - b).abs() <= 1e-12 * b.abs(),
+            "{x}: {a} != {b}"
+        );
+    }
+
+    // x * x overflows, but the reciprocal path keeps the result finite
+    assert!(rational_array(1e200f64, &[1.0, 1.0, 1.0], &[1.0, 1.0, 2.0]).is_finite());
+    assert!(rational_array(1e300f64, &n, &d).is_finite());
+    assert!(rational_array(f64::NAN, &n, &d).is_nan());
+    assert_eq!(
+        rational_array(f64::INFINITY, &[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0]),
+        0.5
+    );
+}
+
+#[test]
+fn test_hybrid_loop_boundary() {
+    // Stress the boundary between the 16-coefficient loop and the tail of the hybrid scheme, where an off-by-one
+    // would misplace or skip a coefficient. Each coefficient is tested in isolation with exact integer arithmetic,
+    // so any misplaced coefficient produces the wrong power of two. With debug assertions or the `paranoid`
+    // feature, the `unreachable` hint in the tail is checked and will panic if ever reached.
+    use fast_polynomial::{poly_array, poly_f, poly_f_n};
+
+    fn check(n: usize, poly_n: impl Fn(&[i128]) -> i128) {
+        for k in 0..n {
+            let mut c = vec![0i128; n];
+            c[k] = 1;
+
+            assert_eq!(poly(2, &c), 1 << k, "n = {n}, k = {k}");
+            assert_eq!(poly_n(&c), 1 << k, "n = {n}, k = {k}");
+            assert_eq!(poly_f(2, n, |i| c[i]), 1 << k, "n = {n}, k = {k}");
+        }
+
+        // every coefficient must be requested, and never beyond the end
+        let mut seen = vec![false; n];
+        poly_f(3i128, n, |i| {
+            seen[i] = true;
+            0
+        });
+        assert!(seen.iter().all(|&s| s), "n = {n}");
+
+        // dense coefficients, compared to a naive reference
+        let c: Vec<i128> = (0..n as i128).map(|i| (i * 7919) % 13 - 6).collect();
+        let reference = c.iter().rev().fold(0i128, |sum, &c| sum * -3 + c);
+        assert_eq!(poly(-3, &c), reference, "n = {n}");
+    }
+
+    macro_rules! check_lengths {
+        ($($n:literal)*) => {$(
+            check($n, |c| {
+                let c: &[i128; $n] = c.try_into().unwrap();
+                assert_eq!(poly_f_n::<_, _, $n>(2, |i| c[i]), poly_array(2, c));
+                poly_array(2, c)
+            });
+        )*};
+    }
+
+    check_lengths!(15 16 17 31 32 33 47 48 49 63 64 65);
+}
+
+#[test]
+fn test_newton_eval() {
+    use fast_polynomial::bases::newton_eval;
+
+    assert_eq!(newton_eval(2.0f64, &[], &[]), 0.0);
+    assert_eq!(newton_eval(2.0f64, &[5.0], &[]), 5.0);
+    assert_eq!(
+        newton_eval(7i64, &[1, 2, 3], &[1, 2]),
+        1 + 2 * 6 + 3 * 6 * 5
+    );
+
+    // divided differences of random points, then evaluate at and between the nodes
+    let mut state = 0x853C_49E6_748F_EA9B;
+    let xs: Vec<f64> = (0..8)
+        .map(|i| i as f64 / 4.0 - 1.0 + xorshift(&mut state) * 0.1)
+        .collect();
+    let ys: Vec<f64> = xs
+        .iter()
+        .map(|_| xorshift(&mut state) * 2.0 - 1.0)
+        .collect();
+
+    let mut c = ys.clone();
+    for j in 1..c.len() {
+        for i in (j..c.len()).rev() {
+            c[i] = (c[i] - c[i - 1]) / (xs[i] - xs[i - j]);
+        }
+    }
+
+    for (&x, &y) in xs.iter().zip(&ys) {
+        assert_feq!(1e-10, newton_eval(x, &c, &xs), y);
+    }
+
+    // a monomial is its own Newton form with all nodes at zero
+    let m = [0.5, -1.0, 0.25, 2.0];
+    for i in 0..20 {
+        let x = i as f64 / 10.0 - 1.0;
+        assert_feq!(1e-12, newton_eval(x, &m, &[0.0; 3]), horners_method(x, &m));
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_newton_eval_missing_nodes() {
+    fast_polynomial::bases::newton_eval(1.0f64, &[1.0, 2.0, 3.0], &[0.0]);
+}
+
+#[test]
+fn test_series_eval() {
+    use fast_polynomial::streaming::SeriesEval;
+
+    let mut state = 0x2545F4914F6CDD1D;
+    let coeffs: Vec<f64> = (0..40).map(|_| xorshift(&mut state) - 0.5).collect();
+
+    for x in [-0.9, -0.5, 0.0, 0.25, 0.8] {
+        let mut series = SeriesEval::new(x);
+        assert_eq!(series.sum(), 0.0);
+
+        for (k, &c) in coeffs.iter().enumerate() {
+            let sum = series.add_term(c);
+
+            assert_eq!(sum, series.sum());
+            assert_eq!(series.terms(), k + 1);
+            assert_feq!(1e-13, sum, horners_method(x, &coeffs[..=k]));
+        }
+    }
+
+    // exact for integers
+    let mut series = SeriesEval::new(3i64);
+    let sums: Vec<i64> = [1, 2, 0, -1]
+        .into_iter()
+        .map(|c| series.add_term(c))
+        .collect();
+    assert_eq!(sums, [1, 7, 7, -20]);
+
+    // the constant term does not depend on x
+    assert_eq!(SeriesEval::new(f64::NAN).add_term(2.0), 2.0);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_poly_array_fused() {
+    use fast_polynomial::fused::{poly_array_std, poly_fused, Std};
+
+    let mut state = 0x9E37_79B9_7F4A_7C15;
+    let c: [f64; 13] = core::array::from_fn(|_| xorshift(&mut state) * 2.0 - 1.0);
+
+    for i in 0..50 {
+        let x = i as f64 / 25.0 - 1.0;
+
+        let expected = c.iter().rev().fold(0.0, |sum: f64, &c| sum.mul_add(x, c));
+        assert_eq!(poly_array_std(x, &c).to_bits(), expected.to_bits());
+        assert_eq!(poly_fused::<Std, _>(x, &c).to_bits(), expected.to_bits());
+        assert_feq!(1e-12, poly_array_std(x, &c), horners_method(x, &c));
+
+        let c32 = c.map(|c| c as f32);
+        let expected = c32
+            .iter()
+            .rev()
+            .fold(0.0, |sum: f32, &c| sum.mul_add(x as f32, c));
+        assert_eq!(poly_array_std(x as f32, &c32).to_bits(), expected.to_bits());
+
+        #[cfg(feature = "libm")]
+        {
+            use fast_polynomial::fused::poly_array_libm;
+
+            assert_eq!(
+                poly_array_libm(x, &c).to_bits(),
+                poly_array_std(x, &c).to_bits()
+            );
+            assert_eq!(
+                poly_array_libm(x as f32, &c32).to_bits(),
+                poly_array_std(x as f32, &c32).to_bits()
+            );
+        }
+    }
+
+    assert_eq!(poly_fused::<Std, f64>(f64::NAN, &[]), 0.0);
+}
+
+#[test]
+fn test_non_commutative() {
+    use fast_polynomial::{poly_array, poly_f};
+    use num_traits::{MulAdd, Zero};
+    use std::ops::{Add, Mul};
+
+    // quaternion with wrapping integer components, which is an exact non-commutative ring
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Quat([i64; 4]);
+
+    impl Add for Quat {
+        type Output = Quat;
+
+        fn add(self, rhs: Quat) -> Quat {
+            Quat(core::array::from_fn(|i| self.0[i].wrapping_add(rhs.0[i])))
+        }
+    }
+
+    impl Mul for Quat {
+        type Output = Quat;
+
+        fn mul(self, rhs: Quat) -> Quat {
+            let ([a1, b1, c1, d1], [a2, b2, c2, d2]) = (self.0, rhs.0);
+            let dot = |p: [i64; 4], q: [i64; 4]| {
+                (0..4).fold(0i64, |s, i| s.wrapping_add(p[i].wrapping_mul(q[i])))
+            };
+
+            Quat([
+                dot([a1, -b1, -c1, -d1], [a2, b2, c2, d2]),
+                dot([a1, b1, c1, -d1], [b2, a2, d2, c2]),
+                dot([a1, -b1, c1, d1], [c2, d2, a2, b2]),
+                dot([a1, b1, -c1, d1], [d2, c2, b2, a2]),
+            ])
+        }
+    }
+
+    impl MulAdd for Quat {
+        type Output = Quat;
+
+        fn mul_add(self, m: Quat, a: Quat) -> Quat {
+            self * m + a
+        }
+    }
+
+    impl Zero for Quat {
+        fn zero() -> Quat {
+            Quat([0; 4])
+        }
+
+        fn is_zero(&self) -> bool {
+            self.0 == [0; 4]
+        }
+    }
+
+    let mut state = 0x2545_F491_4F6C_DD1D_u64;
+    let mut random = || {
+        Quat(core::array::from_fn(|_| {
+            (xorshift(&mut state) * 19.0) as i64 - 9
+        }))
+    };
+
+    let x = random();
+    let c: Vec<Quat> = (0..80).map(|_| random()).collect();
+
+    assert_ne!(x * c[0], c[0] * x);
+
+    for n in 0..=c.len() {
+        let c = &c[..n];
+
+        let mut xk = Quat([1, 0, 0, 0]);
+        let mut expected = Quat::zero();
+        for &ck in c {
+            expected = expected + xk * ck;
+            xk = xk * x;
+        }
+
+        assert_eq!(poly(x, c), expected, "n = {n}");
+        assert_eq!(poly_f(x, n, |i| c[i]), expected, "n = {n}");
+    }
+
+    let c: [Quat; 37] = core::array::from_fn(|i| c[i]);
+    assert_eq!(poly_array(x, &c), poly(x, &c[..]));
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_poly_grid() {
+    use fast_polynomial::many_xs::poly_grid;
+
+    let mut out = [1.0; 4];
+    poly_grid(&[], 0.0, 1.0, 4, &mut out);
+    assert_eq!(out, [0.0; 4]);
+
+    let mut out: [f64; 0] = [];
+    poly_grid(&[1.0, 2.0], 0.0, 1.0, 0, &mut out);
+
+    // exact for integers, including fewer points than coefficients
+    let c = [7i128, -3, 0, 2, -1, 1];
+    for count in [1, 3, 6, 100] {
+        let mut out = vec![0; count];
+        poly_grid(&c, -20, 3, count, &mut out);
+
+        for (i, &y) in out.iter().enumerate() {
+            assert_eq!(y, poly(-20 + 3 * i as i128, &c), "i = {i}");
+        }
+    }
+
+    let c = [0.5, -1.25, 0.75, 0.125];
+    let mut out = vec![0.0; 200];
+    poly_grid(&c, -1.0, 0.01, 200, &mut out);
+
+    for (i, &y) in out.iter().enumerate() {
+        let x = -1.0 + i as f64 * 0.01;
+        assert_feq!(1e-9, y, horners_method(x, &c));
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+#[should_panic]
+fn test_poly_grid_length_mismatch() {
+    fast_polynomial::many_xs::poly_grid(&[1.0, 2.0], 0.0, 1.0, 3, &mut [0.0; 2]);
+}
+
+#[test]
+fn test_array_wrap_tuples() {
+    use fast_polynomial::{many_xs::ArrayWrap, poly_array};
+    use num_traits::MulAdd;
+
+    let a = ArrayWrap::from((1.5f32, -2.0));
+    let m = ArrayWrap::from((2.0f32, 3.0));
+    let c = ArrayWrap::from((0.25f32, 1.0));
+
+    assert_eq!(<(f32, f32)>::from(a.mul_add(m, c)), (3.25, -5.0));
+    assert_eq!(
+        <(f32, f32, f32)>::from(ArrayWrap([1.0, 2.0, 3.0])),
+        (1.0, 2.0, 3.0)
+    );
+    assert_eq!(ArrayWrap::from((1u8, 2, 3, 4)), ArrayWrap([1, 2, 3, 4]));
+
+    let coeffs = [0.9, -0.3, 0.4, 1.6, 0.25];
+    let (y0, y1, y2, y3): (f64, f64, f64, f64) = poly_array(
+        ArrayWrap::from((-1.0, 0.0, 0.5, 2.0)),
+        &coeffs.map(ArrayWrap::splat),
+    )
+    .into();
+
+    for (x, y) in [(-1.0, y0), (0.0, y1), (0.5, y2), (2.0, y3)] {
+        assert_eq!(y, poly_array(x, &coeffs));
+    }
+}
+
+#[test]
+fn test_poly_array_d() {
+    use fast_polynomial::{algebra::differentiate, poly_array, poly_array_d};
+
+    macro_rules! check_lengths {
+        ($($n:literal)*) => {$({
+            let mut state = 0x2545_F491_4F6C_DD1D_u64 ^ $n;
+            let c: [f64; $n] = core::array::from_fn(|_| xorshift(&mut state) * 2.0 - 1.0);
+
+            let mut dc = vec![0.0; $n.max(1) - 1];
+            if $n > 0 {
+                differentiate(&c, &mut dc);
+            }
+
+            for x in [-1.0, -0.7, -0.1, 0.0, 0.3, 0.9, 1.0] {
+                let (y, dy) = poly_array_d(x, &c);
+
+                assert_eq!(y.to_bits(), poly_array(x, &c).to_bits(), "N = {}, x = {}", $n, x);
+                assert_feq!(1e-9 * (1.0 + $n as f64), dy, horners_method(x, &dc));
+            }
+        })*};
+    }
+
+    check_lengths!(0 1 2 3 4 5 8 9 15 16 17 31 32 33 47 48 64 65 100);
+
+    // exact for integers
+    assert_eq!(poly_array_d(3i64, &[5, -4, 0, 2]), (5 - 12 + 54, -4 + 54));
+}
+
+#[cfg(feature = "runtime-detection")]
+#[test]
+fn test_poly_array_dispatch() {
+    use fast_polynomial::dispatch::{fma_detected, poly_array_dispatch};
+    use fast_polynomial::poly_array;
+
+    macro_rules! check_lengths {
+        ($($n:literal)*) => {$({
+            let mut state = 0x2545_F491_4F6C_DD1D_u64 ^ $n;
+            let c: [f64; $n] = core::array::from_fn(|_| xorshift(&mut state) * 2.0 - 1.0);
+
+            for x in [-1.0, -0.7, -0.1, 0.0, 0.3, 0.9, 1.0] {
+                let y = poly_array_dispatch(x, &c);
+
+                if !fma_detected() || cfg!(target_feature = "fma") {
+                    assert_eq!(y.to_bits(), poly_array(x, &c).to_bits());
+                }
+
+                assert_feq!(1e-12 * (1.0 + $n as f64), y, horners_method(x, &c));
+                assert!(poly_array_dispatch(x as f32, &c.map(|c| c as f32)).is_finite());
+            }
+        })*};
+    }
+
+    check_lengths!(0 1 2 3 4 5 7 8 9 15 16 17 31 32 33 64 65 100);
+
+    assert_eq!(fma_detected(), fma_detected());
+
+    // The dispatched evaluation is identical to `poly_array` compiled with `+fma`, whichever way it was built,
+    // so compare against the results of both builds, `(fused, unfused)`, which all differ in the last place.
+    let mut state = 0x2545_F491_4F6C_DD1D_u64;
+    let c33: [f64; 33] = core::array::from_fn(|_| xorshift(&mut state) * 2.0 - 1.0);
+    let c47: [f64; 47] = core::array::from_fn(|_| xorshift(&mut state) * 2.0 - 1.0);
+
+    for (x, y33, y47) in [
+        (
+            -0.7,
+            (0xbfdba301f3a2d775, 0xbfdba301f3a2d773),
+            (0xbfdd574aeb1ff3f8, 0xbfdd574aeb1ff3f6),
+        ),
+        (
+            0.9,
+            (0xbff428df2d88da53, 0xbff428df2d88da54),
+            (0x3ff14514837e68af, 0x3ff14514837e68ae),
+        ),
+    ] {
+        let pick = |(fused, unfused): (u64, u64)| if fma_detected() { fused } else { unfused };
+
+        assert_eq!(poly_array_dispatch(x, &c33).to_bits(), pick(y33));
+        assert_eq!(poly_array_dispatch(x, &c47).to_bits(), pick(y47));
+    }
+}
+
+#[test]
+fn test_poly_multi() {
+    use fast_polynomial::{poly_array, poly_multi};
+
+    let mut state = 0x853C_49E6_748F_EA9B;
+    let polys: [[f64; 37]; 3] =
+        core::array::from_fn(|_| core::array::from_fn(|_| xorshift(&mut state) - 0.5));
+
+    for x in [-1.2, -0.5, 0.0, 0.3, 1.0, f64::NAN] {
+        let ys = poly_multi(x, &polys);
+
+        for (y, c) in ys.iter().zip(&polys) {
+            assert_eq!(y.to_bits(), poly_array(x, c).to_bits());
+        }
+    }
+
+    assert_eq!(poly_multi(2.0f64, &[[]; 2]), [0.0; 2]);
+    assert_eq!(poly_multi::<f64, 3, 0>(2.0, &[]), [0.0f64; 0]);
+    assert_eq!(poly_multi(3i32, &[[1, 2], [0, 1]]), [7, 3]);
+}
+
+#[test]
+fn test_rational_large_x() {
+    // x * x overflows for all of these, but x itself is finite
+    for x in [1.5e154, -1.5e154, 1e200, f64::MAX, -f64::MAX] {
+        let y = rational(x, &[1.0, 2.0, 3.0], &[4.0, 5.0]);
+        assert_feq!(1e-12, y / x, 0.6);
+
+        let y = rational(x, &[1.0, 2.0], &[4.0, 5.0, 6.0]);
+        assert_feq!(1e-12, y * x, 2.0 / 6.0);
+
+        assert_eq!(rational(x, &[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0]), 0.5);
+    }
+}
+
+#[test]
+fn test_hybrid_tail_powers() {
+    use fast_polynomial::poly_f;
+
+    // After the loop over blocks of 16, the sum is scaled by x^j for the remainder j = n % 16,
+    // so with a single coefficient set in the blocks, the result is exact only if that power is.
+    for x in [2i128, -3] {
+        for n in 17..=64usize {
+            let mut c = vec![0i128; n];
+            c[n - 1] = 1;
+
+            let expected = x.pow(n as u32 - 1);
+
+            assert_eq!(poly(x, &c), expected, "x = {x}, n = {n}");
+            assert_eq!(poly_f(x, n, |i| c[i]), expected, "x = {x}, n = {n}");
+
+            // the lowest coefficient of the blocks is multiplied by x^j alone
+            let mut c = vec![0i128; n];
+            c[n % 16] = 1;
+
+            assert_eq!(poly(x, &c), x.pow((n % 16) as u32), "x = {x}, n = {n}");
+        }
+    }
+
+    // for floats, the power must also be multiplied together in the same order as before it was derived
+    let mut state = 0x1F83_D9AB_FB41_BD6B;
+
+    for _ in 0..100 {
+        let x = 4.0 * xorshift(&mut state) - 2.0;
+        let (x2, x4) = (x * x, x * x * (x * x));
+        let x8 = x4 * x4;
+
+        #[rustfmt::skip]
+        let expected = [
+            x, x2, x2 * x, x4, x4 * x, x4 * x2, x4 * x2 * x, x8, x8 * x, x8 * x2,
+            x8 * x2 * x, x8 * x4, x8 * x4 * x, x8 * x4 * x2, (x8 * x4) * (x2 * x),
+        ];
+
+        for (j, expected) in (1..16).zip(expected) {
+            // the block sums to exactly one, and the remainder to zero, leaving only the power
+            let mut c = [0.0f64; 32];
+            c[j] = 1.0;
+
+            assert_eq!(
+                poly(x, &c[..16 + j]).to_bits(),
+                expected.to_bits(),
+                "x = {x}, j = {j}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_poly_with_integral() {
+    use fast_polynomial::poly_with_integral;
+
+    assert_eq!(poly_with_integral(3.0f64, &[]), (0.0, 0.0));
+    assert_eq!(poly_with_integral(3.0f64, &[2.0]), (2.0, 6.0));
+
+    let mut state = 0x9E37_79B9_7F4A_7C15;
+    for n in [2, 5, 16, 17, 33, 70] {
+        let c: Vec<f64> = (0..n).map(|_| xorshift(&mut state) * 2.0 - 1.0).collect();
+        let antiderivative: Vec<f64> = core::iter::once(0.0)
+            .chain(c.iter().enumerate().map(|(k, &c)| c / (k + 1) as f64))
+            .collect();
+
+        for x in [-1.0, -0.6, 0.0, 0.25, 0.9, 1.0] {
+            let (y, area) = poly_with_integral(x, &c);
+
+            assert_eq!(y.to_bits(), poly(x, &c).to_bits(), "n = {n}, x = {x}");
+            assert_feq!(1e-12, area, horners_method(x, &antiderivative));
+        }
+    }
+
+    // types without a conversion from integers
+    assert_eq!(poly_with_integral(2.0f32, &[1.0, 2.0, 3.0]), (17.0, 14.0));
+    // the partial sum of ln(2) = Σ 2^-k / k, in f32
+    assert_feq!(
+        1e-6,
+        poly_with_integral(0.5f32, &[1.0; 20]).1,
+        poly_with_integral(0.5f64, &[1.0; 20]).1 as f32
+    );
+}
+
+#[test]
+fn test_poly_f2() {
+    use fast_polynomial::{poly_f, poly_f2};
+
+    // p_t(x) = Σ (k + t) x^k
+    let g = |k: usize, t: f64| k as f64 + t;
+
+    for n in [0, 1, 4, 17, 40] {
+        for t in [-1.0, 0.0, 2.5] {
+            for x in [-0.9, 0.0, 0.5] {
+                let y = poly_f2(x, t, n, g);
+
+                assert_eq!(y.to_bits(), poly_f(x, n, |k| g(k, t)).to_bits());
+            }
+        }
+    }
+
+    // each coefficient requested exactly once, with t unchanged
+    let mut seen = [0; 20];
+    poly_f2(0.5, 7.0, 20, |k, t| {
+        assert_eq!(t, 7.0);
+        seen[k] += 1;
+        0.0
+    });
+    assert!(seen.iter().all(|&s| s == 1));
+}
+
+#[cfg(all(feature = "alloc", any(feature = "std", feature = "libm")))]
+#[test]
+fn test_preprocessed_poly() {
+    use fast_polynomial::preprocessed::PreprocessedPoly;
+
+    let mut state = 0x5EED_0187;
+
+    for n in 1..=12 {
+        let mut found = 0;
+
+        for _ in 0..50 {
+            let coeffs: Vec<f64> = (0..n).map(|_| xorshift(&mut state) * 2.0 - 1.0).collect();
+
+            let Some(p) = PreprocessedPoly::new(&coeffs) else {
+                continue;
+            };
+
+            found += 1;
+
+            if n > 3 {
+                assert_eq!(p.multiplications(), (n - 1) / 2 + 2);
+            }
+
+            for i in 0..=40 {
+                let x = i as f64 / 20.0 - 1.0;
+                let mag: f64 = poly(x.abs(), &coeffs.iter().map(|c| c.abs()).collect::<Vec<_>>());
+
+                assert!((p.eval(x) - horners_method(x, &coeffs)).abs() <= 1e-9 * mag);
+            }
+        }
+
+        // preprocessing may fail for some polynomials, but not most at these degrees
+        assert!(found >= 45, "only {found} of degree {} preprocessed", n - 1);
+    }
+
+    assert_eq!(PreprocessedPoly::new(&[]).unwrap().eval(2.0), 0.0);
+    assert_eq!(
+        PreprocessedPoly::new(&[1.0, 2.0, 0.0, 0.0])
+            .unwrap()
+            .eval(2.0),
+        5.0
+    );
+    assert!(PreprocessedPoly::new(&[1.0, f64::NAN]).is_none());
+}
+
+#[test]
+fn test_array_wrap_lanewise() {
+    use fast_polynomial::many_xs::ArrayWrap;
+
+    let a = ArrayWrap([1.5f32, -2.0, 0.0, f32::NAN]);
+    let b = ArrayWrap([-1.0f32, 3.0, -0.5, 1.0]);
+
+    assert_eq!(-b, ArrayWrap([1.0, -3.0, 0.5, -1.0]));
+    assert_eq!((a - b).0[..3], [2.5, -5.0, 0.5]);
+    assert_eq!(a.abs().0[..3], [1.5, 2.0, 0.0]);
+    assert!(a.abs().0[3].is_nan());
+
+    // NaN lanes are ignored, like f32::min and f32::max
+    assert_eq!(a.min(b), ArrayWrap([-1.0, -2.0, -0.5, 1.0]));
+    assert_eq!(a.max(b), ArrayWrap([1.5, 3.0, 0.0, 1.0]));
+    assert_eq!(b.max(a), a.max(b));
+
+    let mut c = ArrayWrap([7i32, -9, 4]);
+    c += ArrayWrap([1, 1, 1]);
+    c *= 2;
+    c -= 1;
+    assert_eq!(c, ArrayWrap([15, -17, 9]));
+    assert_eq!(c % 4, ArrayWrap([3, -1, 1]));
+    assert_eq!(10 % ArrayWrap([3, 4]), ArrayWrap([1, 2]));
+    assert_eq!(c.abs(), ArrayWrap([15, 17, 9]));
+    assert_eq!(c.map(|x| x as f64 / 2.0), ArrayWrap([7.5, -8.5, 4.5]));
+}
+
+#[test]
+fn test_poly_array_factored() {
+    use fast_polynomial::{accurate::poly_array_factored, poly_array};
+
+    let mut state = 0xFAC7_0189;
+
+    let c: [f32; 41] = core::array::from_fn(|_| xorshift(&mut state) as f32 + 0.5);
+    let c64 = c.map(f64::from);
+
+    for x in [-50.0f32, -10.0, -1.5, 1.5, 10.0, 50.0] {
+        let (y, k) = poly_array_factored(x, &c);
+
+        assert_eq!(k, 40);
+        assert!(y.is_finite());
+
+        // compare in log-space against f64, where x^40 is still representable
+        let expected = horners_method(f64::from(x), &c64);
+        let log10 = f64::from(y.abs()).log10() + k as f64 * f64::from(x.abs()).log10();
+
+        assert_feq!(1e-5, log10, expected.abs().log10());
+        assert_eq!(
+            y.is_sign_negative() ^ (x < 0.0 && k % 2 == 1),
+            expected < 0.0
+        );
+    }
+
+    for x in [-1.0f32, -0.5, 0.0, 0.75, 1.0] {
+        assert_eq!(poly_array_factored(x, &c), (poly_array(x, &c), 0));
+    }
+
+    // trailing zeros are skipped
+    let (y, k) = poly_array_factored(1e30f32, &[1.0, 2.0, 0.0, 0.0]);
+    assert_eq!((y, k), (2.0, 1));
+
+    assert_eq!(poly_array_factored(5.0f32, &[0.0; 4]), (0.0, 0));
+    assert!(poly_array_factored(f32::NAN, &c).0.is_nan());
+}
+
+mod tape {
+    use std::cell::RefCell;
+    use std::ops::{Add, Mul};
+
+    use num_traits::{MulAdd, Zero};
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    pub enum Node {
+        Const(f64),
+        Var,
+        Add(usize, usize),
+        Mul(usize, usize),
+        MulAdd(usize, usize, usize),
+    }
+
+    thread_local! {
+        pub static TAPE: RefCell<Vec<Node>> = const { RefCell::new(Vec::new()) };
+    }
+
+    /// Handle to a node on the tape, which records operations rather than computing them
+    #[derive(Debug, Clone, Copy)]
+    pub struct Var(pub usize);
+
+    pub fn push(node: Node) -> Var {
+        TAPE.with_borrow_mut(|t| {
+            t.push(node);
+            Var(t.len() - 1)
+        })
+    }
+
+    impl Add for Var {
+        type Output = Var;
+
+        fn add(self, rhs: Var) -> Var {
+            push(Node::Add(self.0, rhs.0))
+        }
+    }
+
+    impl Mul for Var {
+        type Output = Var;
+
+        fn mul(self, rhs: Var) -> Var {
+            push(Node::Mul(self.0, rhs.0))
+        }
+    }
+
+    impl MulAdd for Var {
+        type Output = Var;
+
+        fn mul_add(self, a: Var, b: Var) -> Var {
+            push(Node::MulAdd(self.0, a.0, b.0))
+        }
+    }
+
+    impl Zero for Var {
+        fn zero() -> Var {
+            push(Node::Const(0.0))
+        }
+
+        fn is_zero(&self) -> bool {
+            panic!("tape nodes have no value to compare")
+        }
+    }
+
+    /// Forward pass for the values, then reverse pass for the adjoints, returning `(p, dp/dx)`
+    pub fn run(x: f64, out: Var) -> (f64, f64) {
+        TAPE.with_borrow(|t| {
+            let mut v = vec![0.0; t.len()];
+
+            for (i, node) in t.iter().enumerate() {
+                v[i] = match *node {
+                    Node::Const(c) => c,
+                    Node::Var => x,
+                    Node::Add(a, b) => v[a] + v[b],
+                    Node::Mul(a, b) => v[a] * v[b],
+                    Node::MulAdd(a, b, c) => v[a] * v[b] + v[c],
+                };
+            }
+
+            let mut adj = vec![0.0; t.len()];
+            adj[out.0] = 1.0;
+
+            let mut dx = 0.0;
+
+            for (i, node) in t.iter().enumerate().rev() {
+                match *node {
+                    Node::Const(_) => {}
+                    Node::Var => dx += adj[i],
+                    Node::Add(a, b) => {
+                        adj[a] += adj[i];
+                        adj[b] += adj[i];
+                    }
+                    Node::Mul(a, b) | Node::MulAdd(a, b, _) => {
+                        adj[a] += adj[i] * v[b];
+                        adj[b] += adj[i] * v[a];
+
+                        if let Node::MulAdd(_, _, c) = *node {
+                            adj[c] += adj[i];
+                        }
+                    }
+                }
+            }
+
+            (v[out.0], dx)
+        })
+    }
+}
+
+#[test]
+fn test_poly_tape() {
+    use fast_polynomial::{poly, poly_array};
+    use tape::{Node, Var, TAPE};
+
+    fn check<const N: usize>() {
+        let mut state = 0x7A9E_0190 + N as u64;
+        let c: [f64; N] = core::array::from_fn(|_| xorshift(&mut state) - 0.5);
+        let dc: Vec<f64> = (1..N).map(|k| k as f64 * c[k]).collect();
+
+        for build in [
+            |x: Var, c: &[Var; N]| poly_array(x, c),
+            |x: Var, c: &[Var; N]| poly(x, c),
+        ] {
+            TAPE.with_borrow_mut(|t| t.clear());
+
+            let x = tape::push(Node::Var);
+            let cv = c.map(|c| tape::push(Node::Const(c)));
+            let out = build(x, &cv);
+
+            for x in [-0.9, 0.3, 0.7] {
+                let (y, dy) = tape::run(x, out);
+
+                assert_feq!(1e-12, y, horners_method(x, &c));
+                assert_feq!(1e-11, dy, horners_method(x, &dc));
+            }
+
+            TAPE.with_borrow(|t| {
+                // every computed node but the result is used, so no power of x is computed without being needed
+                let mut used = vec![false; t.len()];
+
+                for node in t {
+                    match *node {
+                        Node::Add(a, b) | Node::Mul(a, b) => (used[a], used[b]) = (true, true),
+                        Node::MulAdd(a, b, c) => (used[a], used[b], used[c]) = (true, true, true),
+                        _ => {}
+                    }
+                }
+
+                assert!((N + 1..out.0).all(|i| used[i]), "unused nodes for N = {N}");
+                assert_eq!(out.0, t.len() - 1);
+
+                // and no power of x is computed twice
+                for (i, a) in t.iter().enumerate() {
+                    assert!(
+                        !t[..i].contains(a) || matches!(a, Node::Const(_)),
+                        "duplicate node for N = {N}"
+                    );
+                }
+            });
+        }
+    }
+
+    macro_rules! check {
+        ($($n:literal),*) => {$(check::<$n>();)*};
+    }
+
+    check!(0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 12, 15, 16, 17, 20, 31, 32, 33, 47, 64, 65, 100, 129);
+}
+
+#[test]
+fn test_poly_even_odd() {
+    use fast_polynomial::poly_even_odd;
+
+    let mut state = 0xE0E0_0191;
+    let c: Vec<f64> = (0..70).map(|_| xorshift(&mut state) - 0.5).collect();
+
+    for n in 0..64 {
+        let even: Vec<f64> = c[..n].iter().copied().step_by(2).collect();
+        let odd: Vec<f64> = c[..n].iter().copied().skip(1).step_by(2).collect();
+
+        for x in [-1.1, -0.5, 0.0, 0.3, 0.9] {
+            assert_feq!(
+                1e-12,
+                poly_even_odd(x, &even, &odd),
+                horners_method(x, &c[..n])
+            );
+        }
+
+        // mismatched lengths are implicitly padded with zeros
+        let (even, odd) = (&c[..n], &c[n..n + 3]);
+        let mut full = vec![0.0; 2 * n.max(3)];
+
+        for (k, &e) in even.iter().enumerate() {
+            full[2 * k] = e;
+        }
+
+        for (k, &o) in odd.iter().enumerate() {
+            full[2 * k + 1] = o;
+        }
+
+        for x in [-0.7, 0.6] {
+            assert_feq!(1e-12, poly_even_odd(x, even, odd), horners_method(x, &full));
+        }
+    }
+
+    assert_eq!(poly_even_odd(f64::NAN, &[2.0], &[]), 2.0);
+    assert!(poly_even_odd(f64::NAN, &[2.0], &[1.0]).is_nan());
+    assert_eq!(poly_even_odd::<f64>(1.0, &[], &[]), 0.0);
+}
+
+#[test]
+fn test_rational_threshold() {
+    use fast_polynomial::{rational_array_threshold, rational_f_threshold};
+
+    let mut state = 0x7E57_0192;
+
+    let n: [f64; 6] = core::array::from_fn(|_| xorshift(&mut state) + 0.5);
+    let d: [f64; 4] = core::array::from_fn(|_| xorshift(&mut state) + 0.5);
+
+    let exact = |x: f64| horners_method(x, &n) / horners_method(x, &d);
+
+    for t in [0.0, 0.25, 1.0, 4.0, -2.0, f64::INFINITY, f64::NAN] {
+        for i in -40..=40 {
+            let x = i as f64 / 8.0;
+
+            let a = rational_array_threshold(x, &n, &d, t);
+            let b = rational_f_threshold(x, n.len(), d.len(), |i| n[i], |i| d[i], t);
+
+            assert_eq!(a.to_bits(), b.to_bits());
+            assert!(
+                (a - exact(x)).abs() <= 1e-13 * exact(x).abs(),
+                "x = {x}, t = {t}"
+            );
+        }
+    }
+
+    // the forward form is used at and below the threshold, and the reciprocal above it
+    let (m, r) = ([1.0, 0.0, 0.0, 0.0], [0.0, 0.0, 0.0, 1.0]);
+
+    assert_eq!(rational_array_threshold(f64::INFINITY, &m, &r, 1e300), 0.0);
+    assert!(rational_array_threshold(f64::INFINITY, &m, &r, f64::INFINITY).is_nan());
+    assert_eq!(rational_array_threshold(0.0, &r, &m, 0.0), 0.0);
+    assert!(rational_array_threshold(f64::NAN, &n, &d, 1.0).is_nan());
+}
+
+#[test]
+fn test_poly_fixed() {
+    use fast_polynomial::poly_fixed;
+
+    let mut state = 0xF1ED_0193;
+
+    for frac_bits in [0, 8, 16, 24, 31] {
+        let one = 1i64 << frac_bits;
+
+        for n in 0..12 {
+            // keep x within [-1, 1) and the coefficients within [-1/16, 1/16), so the sums stay within range
+            let c: Vec<i32> = (0..n)
+                .map(|_| ((xorshift(&mut state) * 2.0 - 1.0) * one as f64 / 16.0) as i32)
+                .collect();
+            let x = ((xorshift(&mut state) * 2.0 - 1.0) * one as f64) as i32;
+
+            let mut sum = 0i64;
+            for &c in c.iter().rev() {
+                sum = (sum * x as i64).div_euclid(one) + c as i64;
+            }
+
+            assert_eq!(poly_fixed(x, &c, frac_bits) as i64, sum);
+
+            // within rounding error of the exact value
+            let exact = horners_method(
+                x as f64 / one as f64,
+                &c.iter().map(|&c| c as f64 / one as f64).collect::<Vec<_>>(),
+            );
+            assert!((sum as f64 / one as f64 - exact).abs() <= n as f64 / one as f64);
+        }
+    }
+
+    // wraps on overflow
+    assert_eq!(poly_fixed(2, &[i32::MAX, 1], 0), i32::MAX.wrapping_add(2));
+    assert_eq!(poly_fixed(-3, &[0, 1], 1), -2);
+}
+
+#[test]
+#[should_panic]
+fn test_poly_fixed_frac_bits() {
+    fast_polynomial::poly_fixed(1, &[1, 2], 32);
+}
+
+#[test]
+fn test_poly_sign_changes() {
+    use fast_polynomial::diagnostics::poly_sign_changes;
+
+    let mut state = 0x5167_0194;
+
+    for n in 0..20 {
+        let c: Vec<f64> = (0..n).map(|_| xorshift(&mut state) - 0.5).collect();
+
+        for x in [-1.5, -0.5, 0.0, 0.25, 2.0] {
+            let mut sums = Vec::new();
+            let mut sum = 0.0;
+
+            for &c in c.iter().rev() {
+                sum = sum * x + c;
+                sums.push(sum);
+            }
+
+            let signs: Vec<bool> = sums
+                .iter()
+                .filter(|&&s| s != 0.0)
+                .map(|&s| s < 0.0)
+                .collect();
+            let expected = signs.windows(2).filter(|w| w[0] != w[1]).count() as u32;
+
+            let (y, changes) = poly_sign_changes(x, &c);
+
+            assert_feq!(1e-12, y, horners_method(x, &c));
+            assert_eq!(changes, expected);
+        }
+    }
+
+    // (y - 1)(y - 2)(y - 3), the quotient at x = 0 is the polynomial itself, with 3 changes
+    let c = [-6.0, 11.0, -6.0, 1.0];
+    assert_eq!(poly_sign_changes(0.0, &c), (-6.0, 3));
+    assert_eq!(poly_sign_changes(4.0, &c), (6.0, 2));
+
+    assert_eq!(poly_sign_changes(1.0, &[]), (0.0, 0));
+    assert_eq!(poly_sign_changes(1.0, &[1.0, f64::NAN, -1.0]).1, 0);
+}
+
+#[test]
+fn test_rational_array_batch() {
+    use fast_polynomial::{
+        many_xs::{rational_array_batch, rational_array_lanes, rational_array_many, ArrayWrap},
+        rational_array,
+    };
+
+    fn check<const P: usize, const Q: usize>(state: &mut u64) {
+        let n: [f64; P] = core::array::from_fn(|_| xorshift(state) + 0.5);
+        let d: [f64; Q] = core::array::from_fn(|_| xorshift(state) + 0.5);
+
+        let mut xs: Vec<f64> = (0..45).map(|_| (xorshift(state) - 0.5) * 20.0).collect();
+        xs.extend([
+            0.0,
+            -0.0,
+            1.0,
+            -1.0,
+            1e200,
+            -1e-200,
+            f64::INFINITY,
+            f64::NAN,
+        ]);
+
+        let mut ys = vec![0.0; xs.len()];
+        rational_array_batch::<8, _, P, Q>(&xs, &n, &d, &mut ys);
+
+        for (&x, &y) in xs.iter().zip(&ys) {
+            let expected = rational_array(x, &n, &d);
+            assert!(
+                y.to_bits() == expected.to_bits() || (y.is_nan() && expected.is_nan()),
+                "x = {x}"
+            );
+        }
+
+        let mut many = vec![0.0; xs.len()];
+        rational_array_many(&xs, &n, &d, &mut many);
+        assert_eq!(
+            many.iter().map(|y| y.to_bits()).collect::<Vec<_>>(),
+            ys.iter().map(|y| y.to_bits()).collect::<Vec<_>>()
+        );
+
+        let lanes = rational_array_lanes(ArrayWrap([2.0, -3.0, 0.5]), &n, &d);
+        assert_eq!(
+            lanes,
+            ArrayWrap([2.0, -3.0, 0.5].map(|x| rational_array(x, &n, &d)))
+        );
+    }
+
+    let mut state = 0xBA7C_0195;
+
+    check::<3, 3>(&mut state);
+    check::<5, 2>(&mut state);
+    check::<2, 6>(&mut state);
+    check::<2, 2>(&mut state);
+    check::<7, 4>(&mut state);
+    check::<1, 9>(&mut state);
+}
+
+#[test]
+#[should_panic]
+fn test_rational_array_batch_length_mismatch() {
+    use fast_polynomial::many_xs::rational_array_batch;
+
+    rational_array_batch::<4, f64, 2, 2>(&[1.0; 5], &[1.0, 2.0], &[3.0, 4.0], &mut [0.0; 4]);
+}
+
+#[test]
+fn test_poly_array_capped() {
+    use fast_polynomial::{poly_array, poly_array_capped};
+
+    fn check<const N: usize>(state: &mut u64) {
+        let c: [f64; N] = core::array::from_fn(|_| xorshift(state) - 0.5);
+
+        // the sign and payload of NaN are unspecified, and can differ once optimized
+        let bits = |y: f64| if y.is_nan() { f64::NAN } else { y }.to_bits();
+
+        for x in [-1.5, -0.5, 0.0, 0.7, f64::INFINITY] {
+            let expected = bits(poly_array(x, &c));
+
+            assert_eq!(bits(poly_array_capped::<_, N, 0>(x, &c)), expected);
+            assert_eq!(bits(poly_array_capped::<_, N, 8>(x, &c)), expected);
+            assert_eq!(bits(poly_array_capped::<_, N, 16>(x, &c)), expected);
+            assert_eq!(
+                bits(poly_array_capped::<_, N, { usize::MAX }>(x, &c)),
+                expected
+            );
+        }
+    }
+
+    let mut state = 0xCA99_0196;
+
+    check::<0>(&mut state);
+    check::<1>(&mut state);
+    check::<5>(&mut state);
+    check::<8>(&mut state);
+    check::<9>(&mut state);
+    check::<16>(&mut state);
+    check::<17>(&mut state);
+    check::<40>(&mut state);
+}
+
+#[test]
+fn test_factorial_basis_eval() {
+    use fast_polynomial::bases::factorial_basis_eval;
+
+    // expand each falling factorial directly
+    fn expanded(x: f64, coeffs: &[f64]) -> f64 {
+        let mut sum = 0.0;
+
+        for (k, &c) in coeffs.iter().enumerate() {
+            sum += c * (0..k).map(|j| x - j as f64).product::<f64>();
+        }
+
+        sum
+    }
+
+    let mut state = 0xFAC7_0197;
+
+    for n in 0..16 {
+        let c: Vec<f64> = (0..n).map(|_| xorshift(&mut state) - 0.5).collect();
+
+        for x in [-2.5, -1.0, 0.0, 0.5, 3.0, 7.25] {
+            let y = factorial_basis_eval(x, &c);
+            let expected = expanded(x, &c);
+
+            assert!(
+                (y - expected).abs() <= 1e-12 * expected.abs().max(1.0),
+                "n = {n}, x = {x}"
+            );
+        }
+    }
+
+    // Σ C(x, k) = 2^x for integer x, where C(x, k) = x^(k) / k!, so with coefficients 20! / k! the result is 20! 2^x
+    let scale: i128 = (1..=20).product();
+    let c: Vec<i128> = (0..=20)
+        .map(|k: i128| scale / (1..=k).product::<i128>())
+        .collect();
+
+    for x in 0..=20i128 {
+        assert_eq!(factorial_basis_eval(x, &c), scale << x);
+    }
+
+    // unsigned types, where every x - k is non-negative for x >= coeffs.len() - 2
+    assert_eq!(factorial_basis_eval(4u32, &[1, 2, 3]), 45);
+    assert_eq!(factorial_basis_eval(1u32, &[1, 2, 3]), 3);
+    assert_eq!(factorial_basis_eval(0u32, &[7]), 7);
+
+    let small: Vec<i128> = (0..=10).collect();
+    let small_u64: Vec<u64> = (0..=10).collect();
+
+    for x in 9..=20 {
+        assert_eq!(
+            factorial_basis_eval(x as u64, &small_u64) as i128,
+            factorial_basis_eval(x as i128, &small)
+        );
+    }
+
+    assert_eq!(factorial_basis_eval::<f64>(1.0, &[]), 0.0);
+    assert_eq!(factorial_basis_eval(f64::NAN, &[2.0]), 2.0);
+}
+
+#[test]
+fn test_poly_slice_scratch() {
+    use fast_polynomial::{many_xs::ArrayWrap, poly_slice_scratch};
+
+    let mut state = 0x5C7A_0198;
+    let c: Vec<f64> = (0..300).map(|_| xorshift(&mut state) - 0.5).collect();
+    let mut scratch = [0.0; 18];
+
+    for n in 0..c.len() {
+        for x in [-1.1, -0.5, 0.0, 0.7, 1.0] {
+            let y = poly_slice_scratch(x, &c[..n], &mut scratch);
+
+            #[cfg(not(feature = "multi-accumulator"))]
+            assert_eq!(y.to_bits(), poly(x, &c[..n]).to_bits(), "n = {n}");
+
+            let h = horners_method(x, &c[..n]);
+            assert_feq!(1e-9 * h.abs().max(1.0), y, h);
+        }
+    }
+
+    // wide element types
+    let cw: Vec<ArrayWrap<16, f64>> = c.iter().map(|&c| ArrayWrap::splat(c)).collect();
+    let xs = ArrayWrap(core::array::from_fn(|i| i as f64 / 16.0 - 0.5));
+    let mut scratch = [ArrayWrap::splat(0.0); 18];
+
+    let ys = poly_slice_scratch(xs, &cw, &mut scratch);
+
+    for (x, y) in xs.0.into_iter().zip(ys.0) {
+        assert_feq!(1e-12, y, horners_method(x, &c));
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_poly_slice_scratch_too_short() {
+    fast_polynomial::poly_slice_scratch(0.5, &[1.0; 48], &mut [0.0; 2]);
+}
+
+#[test]
+fn test_poly_array_with_top_power() {
+    use fast_polynomial::{poly_array, poly_array_with_top_power};
+
+    let mut state = 0x0199_70B5_A1E3;
+    let c: [f64; 70] = core::array::from_fn(|_| xorshift(&mut state) - 0.5);
+
+    macro_rules! check {
+        ($x:expr; $($n:literal)*) => {$({
+            let a: &[f64; $n] = c[..$n].try_into().unwrap();
+
+            let (y, top) = poly_array_with_top_power($x, a);
+
+            assert_eq!(y.to_bits(), poly_array($x, a).to_bits());
+
+            let expected = $x.powi(($n as i32 - 1).max(0));
+            if top != expected {
+                assert_feq!(1e-13 * expected.abs(), top, expected);
+            }
+        })*};
+    }
+
+    for x in [-1.1f64, -0.5, -0.0, 0.3, 0.99, 2.0] {
+        check!(x; 0 1 2 3 4 5 6 7 8 9 15 16 17 18 20 31 32 33 47 49 64 65 70);
+    }
+
+    // exact for powers of two
+    assert_eq!(
+        poly_array_with_top_power(2.0f64, &[0.0; 50]).1,
+        2f64.powi(49)
+    );
+    assert_eq!(poly_array_with_top_power(3i64, &[1; 8]), (3280, 2187));
+}
+
+#[test]
+fn test_poly_dyn() {
+    use fast_polynomial::{poly_dyn, CoeffSource};
+
+    /// Coefficients computed by a closure chosen at runtime
+    struct Plugin(usize, Box<dyn Fn(usize) -> f64>);
+
+    impl CoeffSource for Plugin {
+        fn len(&self) -> usize {
+            self.0
+        }
+
+        fn coeff(&self, i: usize) -> f64 {
+            assert!(i < self.0);
+            (self.1)(i)
+        }
+    }
+
+    let mut state = 0x0200_D1A5_EB1E;
+    let c: Vec<f64> = (0..70).map(|_| xorshift(&mut state) - 0.5).collect();
+
+    for n in [0, 1, 2, 5, 16, 17, 33, 70] {
+        let c = c[..n].to_vec();
+        let plugin = Plugin(
+            n,
+            Box::new({
+                let c = c.clone();
+                move |i| c[i]
+            }),
+        );
+
+        #[allow(unused_mut)]
+        let mut sources: Vec<Box<dyn CoeffSource>> = vec![Box::new(plugin)];
+
+        #[cfg(feature = "alloc")]
+        sources.push(Box::new(c.clone()));
+
+        for x in [-1.1, -0.5, 0.0, 0.3, 1.0] {
+            for src in &sources {
+                assert_eq!(src.len(), n);
+                assert_eq!(src.is_empty(), n == 0);
+                assert_eq!(poly_dyn(x, &**src).to_bits(), poly(x, &c).to_bits());
+            }
+        }
+    }
+
+    // other element types through the explicit parameter
+    let ints: &dyn CoeffSource<i32> = &[1, 2, 3];
+    assert_eq!(poly_dyn(2, ints), 17);
+}